@@ -0,0 +1,104 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::wordle::{
+    erase_letter, letter_count, next_allowed, typed_into, GuessResult, Wordle, ANSWERS,
+};
+
+/// Several games with different answers played at once, every guess going to
+/// each board that isn't solved yet, with an extra guess for every board.
+pub struct Boards {
+    pub boards: Vec<Wordle>,
+    pub curr: String,
+    pub seed: u64,
+}
+
+impl Boards {
+    /// Starts `count` boards with answers picked by `seed`, no two the same,
+    /// moving on from rude ones unless `allow_rude`.
+    pub fn with_seed(count: usize, seed: u64, allow_rude: bool) -> Self {
+        let mut answers: Vec<&str> = Vec::new();
+
+        for picked in ANSWERS.choose_multiple(&mut StdRng::seed_from_u64(seed), count) {
+            let start = ANSWERS.iter().position(|answer| answer == picked).unwrap();
+            answers.push(next_allowed(&ANSWERS, start, allow_rude, &answers));
+        }
+
+        let boards = answers
+            .into_iter()
+            .map(|answer| {
+                let mut board = Wordle::with_answer(answer);
+                board.max_guesses = count + 5;
+                board
+            })
+            .collect();
+
+        Self {
+            boards,
+            curr: String::new(),
+            seed,
+        }
+    }
+
+    pub fn input(&mut self, c: char) {
+        if let Some(typed) = typed_into(&self.curr, c) {
+            if letter_count(&typed) <= self.boards[0].length() {
+                self.curr = typed;
+            }
+        }
+    }
+
+    pub fn erase(&mut self) {
+        erase_letter(&mut self.curr);
+    }
+
+    /// Plays the current guess on every board still being played, if it is a known word.
+    pub fn guess(&mut self) -> GuessResult {
+        let mut result = GuessResult::Accepted;
+
+        for board in self.boards.iter_mut().filter(|board| board.won().is_none()) {
+            board.curr.clone_from(&self.curr);
+            result = board.guess();
+
+            // every board accepts the same words, so the first one speaks for all
+            if !result.is_accepted() {
+                board.curr.clear();
+                return result;
+            }
+        }
+
+        self.curr.clear();
+        result
+    }
+
+    /// The number of guesses each board took, for the ones that are solved.
+    pub fn solved_in(&self) -> Vec<Option<usize>> {
+        self.boards
+            .iter()
+            .map(|board| (board.won() == Some(true)).then_some(board.guesses().len()))
+            .collect()
+    }
+
+    /// Guesses made so far, counting them once however many boards they went to.
+    pub fn guesses(&self) -> usize {
+        self.boards
+            .iter()
+            .map(|board| board.guesses().len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Won once every board is solved, lost once a board runs out of guesses.
+    pub fn won(&self) -> Option<bool> {
+        let results: Vec<Option<bool>> = self.boards.iter().map(Wordle::won).collect();
+
+        if results.contains(&Some(false)) {
+            Some(false)
+        } else if results.iter().all(|&won| won == Some(true)) {
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crossterm::event::KeyCode;
+use serde::Deserialize;
+
+use crate::theme::{BorderStyle, KeyboardLayout, ThemeConfig};
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Never show the opening tip or the tutorial to new players.
+    pub hide_tips: bool,
+    /// Never remind you of a daily streak that's on the line.
+    pub hide_streak: bool,
+    /// Never show the status bar along the bottom of the game.
+    pub hide_status_bar: bool,
+    /// Start every game in hard mode, as with `--hard`.
+    pub hard: bool,
+    /// Turn off animations, as with `--no-animation`.
+    pub no_animation: bool,
+    /// Lines the grid is drawn with when `--border` isn't given.
+    pub border: Option<BorderStyle>,
+    /// Keyboard layout when `--layout` isn't given.
+    pub layout: Option<KeyboardLayout>,
+    /// Word lists to use when `--answers` and `--guesses` aren't given.
+    pub answers: Option<PathBuf>,
+    pub guesses: Option<PathBuf>,
+    /// Where `wordle update-words` downloads the lists from, each list's name
+    /// appended after a `/`.
+    pub word_lists_url: Option<String>,
+    pub keys: KeyMap,
+    pub theme: ThemeConfig,
+    /// Server to send daily results to and show the standings from.
+    #[cfg(feature = "online-leaderboard")]
+    pub leaderboard: Option<crate::leaderboard::Server>,
+}
+
+impl Config {
+    /// Location of the config file, usually `~/.config/wordle/config.toml`.
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("wordle").join("config.toml"))
+    }
+
+    /// Loads the config file, falling back to the defaults when there is none.
+    pub fn load() -> io::Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+
+        let config = match fs::read_to_string(&path) {
+            Ok(config) => config,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+
+        let config: Self = toml::from_str(&config).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), err.message()),
+            )
+        })?;
+
+        match config.keys.clash() {
+            Some(clash) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {clash}", path.display()),
+            )),
+            None => Ok(config),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Submit,
+    Erase,
+    Quit,
+    Restart,
+    Category,
+    Pause,
+    Complete,
+    Candidates,
+    Anagram,
+    Hard,
+    Assist,
+    Frequency,
+    Undo,
+    Hint,
+    Help,
+    /// Moves to the choice above in a menu.
+    Up,
+    /// Moves to the choice below in a menu.
+    Down,
+}
+
+impl Action {
+    /// What the action does, in a few words for the help screen.
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::Submit => "submit guess",
+            Action::Erase => "erase letter",
+            Action::Quit => "give up",
+            Action::Restart => "new game",
+            Action::Category => "category hint",
+            Action::Pause => "pause",
+            Action::Complete => "finish word",
+            Action::Candidates => "answers left",
+            Action::Anagram => "anagram hint",
+            Action::Hard => "hard mode",
+            Action::Assist => "suggestions",
+            Action::Frequency => "letter counts",
+            Action::Undo => "take back guess",
+            Action::Hint => "reveal a letter",
+            Action::Help => "this help",
+            Action::Up => "menu up",
+            Action::Down => "menu down",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeyMap {
+    pub submit: Key,
+    pub erase: Key,
+    pub quit: Key,
+    pub restart: Key,
+    pub category: Key,
+    pub pause: Key,
+    pub complete: Key,
+    pub candidates: Key,
+    pub anagram: Key,
+    pub hard: Key,
+    pub assist: Key,
+    pub frequency: Key,
+    pub undo: Key,
+    pub hint: Key,
+    pub help: Key,
+    pub up: Key,
+    pub down: Key,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            submit: Key(KeyCode::Enter),
+            erase: Key(KeyCode::Backspace),
+            quit: Key(KeyCode::Esc),
+            restart: Key(KeyCode::F(5)),
+            category: Key(KeyCode::F(2)),
+            pause: Key(KeyCode::Tab),
+            complete: Key(KeyCode::Right),
+            candidates: Key(KeyCode::F(3)),
+            anagram: Key(KeyCode::F(4)),
+            hard: Key(KeyCode::F(6)),
+            assist: Key(KeyCode::F(7)),
+            frequency: Key(KeyCode::F(8)),
+            undo: Key(KeyCode::F(9)),
+            hint: Key(KeyCode::Char('?')),
+            help: Key(KeyCode::F(1)),
+            up: Key(KeyCode::Up),
+            down: Key(KeyCode::Down),
+        }
+    }
+}
+
+impl KeyMap {
+    /// Every action along with the key it is bound to.
+    pub fn bindings(&self) -> [(Key, Action); 17] {
+        [
+            (self.submit, Action::Submit),
+            (self.erase, Action::Erase),
+            (self.quit, Action::Quit),
+            (self.restart, Action::Restart),
+            (self.category, Action::Category),
+            (self.pause, Action::Pause),
+            (self.complete, Action::Complete),
+            (self.candidates, Action::Candidates),
+            (self.anagram, Action::Anagram),
+            (self.hard, Action::Hard),
+            (self.assist, Action::Assist),
+            (self.frequency, Action::Frequency),
+            (self.undo, Action::Undo),
+            (self.hint, Action::Hint),
+            (self.help, Action::Help),
+            (self.up, Action::Up),
+            (self.down, Action::Down),
+        ]
+    }
+
+    /// Two actions bound to the same key, which would leave one of them out of reach.
+    pub fn clash(&self) -> Option<String> {
+        let bindings = self.bindings();
+
+        bindings
+            .iter()
+            .enumerate()
+            .find_map(|(idx, (key, action))| {
+                bindings[idx + 1..]
+                    .iter()
+                    .find(|(other, _)| other == key)
+                    .map(|(_, other)| {
+                        format!(
+                            "`{}` is bound to both {} and {}",
+                            key.name(),
+                            action.label(),
+                            other.label()
+                        )
+                    })
+            })
+    }
+
+    pub fn action(&self, code: KeyCode) -> Option<Action> {
+        self.bindings()
+            .into_iter()
+            .find_map(|(key, action)| (key.0 == code).then_some(action))
+    }
+}
+
+/// A key an action can be bound to, written as its name (`Enter`, `F5`) or a single symbol.
+///
+/// Letters are refused since they would be swallowed before reaching the guess.
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Key(pub KeyCode);
+
+impl Key {
+    /// The key's name, as it can be written in the config file.
+    pub fn name(self) -> String {
+        match self.0 {
+            KeyCode::Enter => String::from("Enter"),
+            KeyCode::Backspace => String::from("Backspace"),
+            KeyCode::Esc => String::from("Esc"),
+            KeyCode::Tab => String::from("Tab"),
+            KeyCode::Delete => String::from("Delete"),
+            KeyCode::Insert => String::from("Insert"),
+            KeyCode::Home => String::from("Home"),
+            KeyCode::End => String::from("End"),
+            KeyCode::PageUp => String::from("PageUp"),
+            KeyCode::PageDown => String::from("PageDown"),
+            KeyCode::Up => String::from("Up"),
+            KeyCode::Down => String::from("Down"),
+            KeyCode::Left => String::from("Left"),
+            KeyCode::Right => String::from("Right"),
+            KeyCode::Char(' ') => String::from("Space"),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::F(n) => format!("F{n}"),
+            code => format!("{code:?}"),
+        }
+    }
+}
+
+impl TryFrom<String> for Key {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        let code = match name.to_ascii_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "backspace" => KeyCode::Backspace,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" => KeyCode::Insert,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "space" => KeyCode::Char(' '),
+            key => match key.strip_prefix('f').map(str::parse) {
+                Some(Ok(n @ 1..=12)) => KeyCode::F(n),
+                _ => {
+                    let mut chars = name.chars();
+
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) if c.is_alphabetic() => {
+                            return Err(format!(
+                                "letter key `{c}` can't be bound, it's used for typing guesses"
+                            ));
+                        }
+                        (Some(c), None) => KeyCode::Char(c),
+                        _ => return Err(format!("unknown key `{name}`")),
+                    }
+                }
+            },
+        };
+
+        Ok(Key(code))
+    }
+}
@@ -0,0 +1,50 @@
+//! Definitions looked up online, for builds with the `online-definitions` feature.
+
+use std::process::{Command, Stdio};
+
+use serde::Deserialize;
+
+/// Where a word's entries are fetched from, with the word appended.
+const API: &str = "https://api.dictionaryapi.dev/api/v2/entries/en/";
+
+/// How long to wait for an answer before going without, in seconds.
+const TIMEOUT: &str = "3";
+
+#[derive(Deserialize)]
+struct Entry {
+    meanings: Vec<Meaning>,
+}
+
+#[derive(Deserialize)]
+struct Meaning {
+    definitions: Vec<Definition>,
+}
+
+#[derive(Deserialize)]
+struct Definition {
+    definition: String,
+}
+
+/// The first definition the dictionary has for `word`, or `None` when it has
+/// none or can't be reached, such as when `curl` isn't installed.
+pub fn lookup(word: &str) -> Option<String> {
+    let output = Command::new("curl")
+        .args(["--silent", "--fail", "--max-time", TIMEOUT])
+        .arg(format!("{API}{word}"))
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let entries: Vec<Entry> = serde_json::from_slice(&output.stdout).ok()?;
+
+    entries
+        .into_iter()
+        .flat_map(|entry| entry.meanings)
+        .flat_map(|meaning| meaning.definitions)
+        .map(|definition| definition.definition)
+        .next()
+}
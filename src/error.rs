@@ -0,0 +1,114 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// What can keep the game from starting, each saying what to do about it.
+#[derive(Debug)]
+pub enum WordleError {
+    /// A word list, by name or path, with no words in it.
+    EmptyList(String),
+    /// A line of a word list that isn't a word the list can have.
+    BadEntry {
+        list: String,
+        line: usize,
+        entry: String,
+    },
+    /// An answer missing from the guess list, which could never be guessed.
+    Unguessable(String),
+    /// A word in a list of another length than the answers.
+    MixedLengths {
+        list: String,
+        word: String,
+        length: usize,
+    },
+    /// A word list that isn't in any of the directories searched for it.
+    MissingList {
+        name: String,
+        searched: Vec<PathBuf>,
+    },
+    /// A download that failed, with why.
+    Download {
+        url: String,
+        reason: String,
+    },
+    /// Output that doesn't go to a terminal, which the game needs to draw in.
+    NotATerminal,
+    /// A terminal that doesn't say how big it is, or says it has no room at all.
+    UnknownTerminalSize,
+    /// A file that couldn't be read or written.
+    File {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for WordleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordleError::EmptyList(list) => {
+                write!(
+                    f,
+                    "the `{list}` word list is empty, it needs at least one word"
+                )
+            }
+            WordleError::BadEntry { list, line, entry } => write!(
+                f,
+                "line {line} of the `{list}` word list: bad entry `{entry}`, \
+                 every line has to start with a word of 4 to 8 letters in lowercase"
+            ),
+            WordleError::Unguessable(answer) => write!(
+                f,
+                "the answer `{answer}` isn't in the guess list, so it could never \
+                 be guessed; add it to the guesses"
+            ),
+            WordleError::MixedLengths { list, word, length } => write!(
+                f,
+                "the `{list}` word list has `{word}`, which isn't {length} letters \
+                 long like the answers; every word has to be the same length"
+            ),
+            WordleError::MissingList { name, searched } => {
+                let searched: Vec<String> = searched
+                    .iter()
+                    .map(|dir| dir.display().to_string())
+                    .collect();
+
+                write!(
+                    f,
+                    "couldn't find the `{name}` word list in any of: {}; put it in \
+                     one of them, or point WORDLE_WORDLISTS at where it is",
+                    searched.join(", ")
+                )
+            }
+            WordleError::Download { url, reason } => write!(f, "{url}: {reason}"),
+            WordleError::NotATerminal => write!(
+                f,
+                "the game needs a terminal to draw in; to play through a pipe, \
+                 use --plain or --json"
+            ),
+            WordleError::UnknownTerminalSize => write!(
+                f,
+                "the terminal didn't say how big it is; try another terminal, or \
+                 play with --plain or --accessible"
+            ),
+            WordleError::File { path, source } => write!(f, "{}: {source}", path.display()),
+            WordleError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for WordleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WordleError::File { source, .. } => Some(source),
+            WordleError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for WordleError {
+    fn from(err: io::Error) -> Self {
+        WordleError::Io(err)
+    }
+}
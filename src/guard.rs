@@ -0,0 +1,107 @@
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, Once};
+
+use crossterm::cursor::{Hide, Show};
+use crossterm::event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste};
+use crossterm::execute;
+use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+
+/// Whether the terminal is taken over, so that it is only handed back once.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+static SIGNALS: Once = Once::new();
+
+/// Warnings held back while the terminal is taken over, for `restore` to print.
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// The terminal in raw mode on the alternate screen, for as long as the guard
+/// lives. Dropping it hands the terminal back, whichever way the program leaves.
+pub struct TerminalGuard(());
+
+impl TerminalGuard {
+    pub fn new() -> io::Result<Self> {
+        SIGNALS.call_once(restore_on_signals);
+        reenter()?;
+        Ok(Self(()))
+    }
+}
+
+/// Takes the terminal over again after `restore`, with pasted text coming in
+/// whole rather than as keys.
+pub fn reenter() -> io::Result<()> {
+    terminal::enable_raw_mode()?;
+    ACTIVE.store(true, Ordering::SeqCst);
+    execute!(
+        io::stdout(),
+        EnterAlternateScreen,
+        Hide,
+        EnableBracketedPaste
+    )
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore();
+    }
+}
+
+/// Hands the terminal back to the shell if it is taken over, ignoring errors
+/// as there is nothing left to do about them on the way out, then prints the
+/// warnings held back in the meantime.
+pub fn restore() {
+    if ACTIVE.swap(false, Ordering::SeqCst) {
+        let _ = terminal::disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            DisableBracketedPaste,
+            DisableMouseCapture,
+            LeaveAlternateScreen,
+            Show
+        );
+    }
+
+    if let Ok(mut warnings) = WARNINGS.lock() {
+        for warning in warnings.drain(..) {
+            eprintln!("{warning}");
+        }
+    }
+}
+
+/// Prints `warning` to stderr, or holds it back until the terminal is handed
+/// back while it is taken over, as it would land in the middle of the game's
+/// screen in raw mode. The same warning is only held back once.
+pub fn warn(warning: String) {
+    if !ACTIVE.load(Ordering::SeqCst) {
+        eprintln!("{warning}");
+        return;
+    }
+
+    if let Ok(mut warnings) = WARNINGS.lock() {
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+}
+
+/// Hands the terminal back before the program is stopped by Ctrl-C outside raw
+/// mode, by `kill` or by the terminal closing, which would leave it as it was.
+#[cfg(unix)]
+fn restore_on_signals() {
+    use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+    use signal_hook::iterator::Signals;
+
+    let Ok(mut signals) = Signals::new([SIGHUP, SIGINT, SIGTERM]) else {
+        return;
+    };
+
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            restore();
+            std::process::exit(128 + signal);
+        }
+    });
+}
+
+#[cfg(not(unix))]
+fn restore_on_signals() {}
@@ -0,0 +1,119 @@
+//! Game events passed on to a command given with `--event-hook`, such as one
+//! that shows a desktop notification or appends them to a log.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+use serde_json::{json, Value};
+
+use wordle::{clues_to_string, Clue, GameObserver, GuessResult, Wordle};
+
+pub struct EventHook {
+    events: Option<Sender<Value>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl EventHook {
+    /// Runs `command` through the shell for every event, on a thread of its
+    /// own so that a slow command doesn't hold up the game, with the event as
+    /// a line of JSON on its standard input. Events are sent one at a time, in
+    /// the order they happened.
+    pub fn new(command: String) -> Self {
+        let (events, rx) = mpsc::channel::<Value>();
+
+        let worker = thread::spawn(move || {
+            for event in rx {
+                let child = shell(&command)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .spawn();
+
+                // a command that won't run misses this event, not the ones after it
+                let Ok(mut child) = child else {
+                    continue;
+                };
+
+                if let Some(mut stdin) = child.stdin.take() {
+                    let _ = writeln!(stdin, "{event}");
+                }
+
+                let _ = child.wait();
+            }
+        });
+
+        Self {
+            events: Some(events),
+            worker: Some(worker),
+        }
+    }
+
+    fn send(&self, event: Value) {
+        if let Some(events) = &self.events {
+            let _ = events.send(event);
+        }
+    }
+}
+
+/// Waits for the events still queued, so that the last of a game isn't lost
+/// when the program ends right after it.
+impl Drop for EventHook {
+    fn drop(&mut self) {
+        self.events.take();
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl GameObserver for EventHook {
+    fn on_guess(&mut self, game: &Wordle, guess: &str, clues: &[Clue]) {
+        self.send(json!({
+            "event": "guess",
+            "row": game.guesses().len(),
+            "guess": guess,
+            "clues": clues_to_string(clues),
+        }));
+    }
+
+    fn on_invalid(&mut self, _: &Wordle, guess: &str, why: &GuessResult) {
+        self.send(json!({
+            "event": "invalid",
+            "guess": guess,
+            "reason": why.rejection(),
+        }));
+    }
+
+    fn on_game_end(&mut self, game: &Wordle) {
+        let outcome = match game.won() {
+            Some(true) => "won",
+            Some(false) => "lost",
+            None => "forfeit",
+        };
+
+        self.send(json!({
+            "event": "end",
+            "outcome": outcome,
+            "answer": game.answer,
+            "guesses": game.guesses().len(),
+            "day": game.day,
+            "seed": game.seed,
+        }));
+    }
+}
+
+/// `command` to run through the system's shell.
+fn shell(command: &str) -> Command {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+
+    let mut shell = Command::new(shell);
+    shell.args([flag, command]);
+    shell
+}
@@ -0,0 +1,92 @@
+//! Daily results shared with a leaderboard server that a group runs for
+//! itself, for builds with the `online-leaderboard` feature.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+
+/// How long to wait for the server before going without, in seconds.
+const TIMEOUT: &str = "3";
+
+/// The server results go to, from the `[leaderboard]` table of the config file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Server {
+    /// Where the server is, such as `https://wordle.example.com`.
+    pub url: String,
+    /// Name to play under, which is only ever sent hashed.
+    pub name: String,
+}
+
+/// A player's result for the day, as sent to the server and listed by it.
+#[derive(Serialize, Deserialize)]
+pub struct Entry {
+    pub player: String,
+    pub guesses: usize,
+    pub won: bool,
+    pub seconds: u64,
+}
+
+impl Server {
+    /// The player as the server knows them, an FNV-1a hash of their name, so
+    /// that the same name always comes out the same.
+    pub fn player(&self) -> String {
+        let hash = self.name.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+
+        format!("{hash:016x}")
+    }
+
+    /// Sends the result for daily puzzle `day`, giving whether the server took it.
+    pub fn submit(&self, day: i64, entry: &Entry) -> bool {
+        let Ok(body) = serde_json::to_vec(entry) else {
+            return false;
+        };
+
+        let args = [
+            "--request",
+            "POST",
+            "--header",
+            "Content-Type: application/json",
+            "--data-binary",
+            "@-",
+        ];
+
+        curl(&self.results(day), &args, Some(&body)).is_some()
+    }
+
+    /// Everyone's results for daily puzzle `day`, in the order the server ranks
+    /// them, or `None` when it can't be reached.
+    pub fn standings(&self, day: i64) -> Option<Vec<Entry>> {
+        let body = curl(&self.results(day), &[], None)?;
+        serde_json::from_slice(&body).ok()
+    }
+
+    fn results(&self, day: i64) -> String {
+        format!("{}/days/{day}/results", self.url.trim_end_matches('/'))
+    }
+}
+
+/// Fetches `url` with `args`, sending `body` if there is one, or gives `None`
+/// when that fails, such as when `curl` isn't installed.
+fn curl(url: &str, args: &[&str], body: Option<&[u8]>) -> Option<Vec<u8>> {
+    let mut child = Command::new("curl")
+        .args(["--silent", "--fail", "--max-time", TIMEOUT])
+        .args(args)
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // dropped once written, so that curl sees the end of it
+    let mut stdin = child.stdin.take()?;
+    stdin.write_all(body.unwrap_or_default()).ok()?;
+    drop(stdin);
+
+    let output = child.wait_with_output().ok()?;
+    output.status.success().then_some(output.stdout)
+}
@@ -0,0 +1,60 @@
+//! The game engine on its own, without the terminal front end: starting games,
+//! scoring guesses against the answer, the word lists and the solver.
+//!
+//! The names below are the ones to build another front end on: a [`Game`] is
+//! typed into and guessed with, each guess giving a [`GuessResult`], and the
+//! [`Guess`] it made scored with a [`LetterState`] per letter.
+
+mod boards;
+mod error;
+mod marathon;
+mod observer;
+mod pattern;
+pub mod solver;
+mod trie;
+mod wordle;
+#[cfg(feature = "runtime-wordlists")]
+pub mod wordlists;
+
+pub use boards::Boards;
+pub use error::WordleError;
+pub use marathon::Marathon;
+pub use observer::{GameObserver, Observers};
+pub use pattern::{pattern, score, Pattern, PatternTable};
+pub use trie::WordTrie;
+pub use wordle::{
+    categories, category, check_lists, closeness, clues_to_string, definition, frequency_rank,
+    is_list_word, is_win, letter_count, letters_in_common, parse_emoji_grid, possible_answers,
+    score_guess, uncommon_common_word, upper, upper_letter, use_custom_list, use_language,
+    use_length, word_length, words_in_category, Clue, Guess, GuessResult, Hint, Schedule, WordList,
+    Wordle, ANSWERS, GUESSES, GUESS_LIST, WORD_LENGTHS,
+};
+
+/// A game in progress, from its answer to the guess being typed.
+pub use wordle::Wordle as Game;
+
+/// The feedback a letter of a guess gets.
+pub use wordle::Clue as LetterState;
+
+#[cfg(test)]
+mod tests {
+    use crate::{Game, Guess, GuessResult, LetterState};
+
+    #[test]
+    fn a_game_can_be_played_through_the_front_end_names() {
+        let mut game = Game::with_answer("crane");
+
+        for c in "slate".chars() {
+            game.input(c);
+        }
+        assert_eq!(game.guess(), GuessResult::Accepted);
+
+        let guess: Guess = game.last_guess().unwrap();
+        assert_eq!(guess.word, "slate");
+        assert_eq!(guess.clues[2], LetterState::Green);
+
+        game.paste("xxxxx");
+        assert_eq!(game.guess(), GuessResult::NotAWord);
+        assert_eq!(game.won(), None);
+    }
+}
@@ -1,212 +1,6434 @@
-use std::collections::{HashMap, HashSet};
-use std::io::Write;
-use std::time::Duration;
+mod config;
+#[cfg(feature = "online-definitions")]
+mod dictionary;
+mod guard;
+mod hook;
+#[cfg(feature = "online-leaderboard")]
+mod leaderboard;
+mod mirror;
+mod race;
+mod save;
+mod screen;
+mod stats;
+mod theme;
+mod timer;
+mod update;
 
-use crossterm::style::{Color, ContentStyle, PrintStyledContent, StyledContent, Stylize};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use crossterm::style::{PrintStyledContent, StyledContent, Stylize};
 use crossterm::{
-    cursor::{Hide, MoveTo, Show},
-    event::{self, Event, KeyCode, KeyEvent},
+    cursor::MoveTo,
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute, queue,
     style::Print,
-    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal,
 };
-use lazy_static::lazy_static;
-use rand::seq::SliceRandom;
+use unicode_width::UnicodeWidthStr;
+
+use config::{Action, Config};
+use guard::TerminalGuard;
+use mirror::Mirror;
+use race::{Race, RaceEvent};
+use save::SavedGame;
+use screen::{Frame, Screen};
+use stats::{Achievement, GameKind, GameRecord, Outcome, Stats};
+use theme::{BorderStyle, ColorSupport, KeyboardLayout, Theme, ThemeName};
+use timer::Stopwatch;
+use wordle::{
+    categories, closeness, clues_to_string, definition, frequency_rank, is_win, letter_count,
+    letters_in_common, parse_emoji_grid, possible_answers, score_guess, solver,
+    uncommon_common_word, upper, upper_letter, words_in_category, Boards, Clue, GameObserver,
+    GuessResult, Hint, Marathon, Observers, Schedule, WordList, Wordle, WordleError, ANSWERS,
+    GUESSES, GUESS_LIST,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum Mode {
+    /// Green and yellow tiles for every letter
+    #[default]
+    Classic,
+    /// Only the number of letters in common with the answer
+    Jotto,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum ExportFormat {
+    /// A line per game
+    #[default]
+    Csv,
+    /// Every game along with the totals, as one object
+    Json,
+}
+
+/// The language the words are in, by its ISO 639-1 code.
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum Language {
+    #[default]
+    #[value(name = "en")]
+    English,
+    /// French, accented letters included
+    #[value(name = "fr")]
+    French,
+}
+
+impl Language {
+    fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+        }
+    }
+
+    /// The keyboard the language is typed on, where it isn't the usual one.
+    fn layout(self) -> Option<KeyboardLayout> {
+        match self {
+            Language::English => None,
+            Language::French => Some(KeyboardLayout::Azerty),
+        }
+    }
+}
+
+/// How well known the answers picked are, going by how often they are used.
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum Difficulty {
+    /// Everyday words, among the 3000 most used
+    Easy,
+    #[default]
+    Normal,
+    /// Rarer words, outside the 10000 most used
+    Hard,
+}
+
+impl Difficulty {
+    fn allows(self, answer: &str) -> bool {
+        match self {
+            Difficulty::Easy => frequency_rank(answer).is_some_and(|rank| rank <= 3000),
+            Difficulty::Normal => true,
+            Difficulty::Hard => frequency_rank(answer).is_none_or(|rank| rank > 10000),
+        }
+    }
+}
+
+/// How `simulate` picks each guess.
+#[derive(Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+enum Strategy {
+    /// The guess expected to give the most information, as `solve` plays
+    #[default]
+    Entropy,
+    /// The possible answer made of the most common letters among them
+    Frequency,
+    /// Any of the possible answers, as a baseline
+    Random,
+}
+
+impl Strategy {
+    /// Plays a game of up to six guesses against `answer`, with `game` seeding
+    /// the random picks so that runs can be repeated.
+    fn play(self, answer: &str, game: u64) -> Vec<&'static str> {
+        match self {
+            Strategy::Entropy => solver::play(answer, 6, solver::information_guess),
+            Strategy::Frequency => solver::play(answer, 6, solver::frequency_guess),
+            Strategy::Random => {
+                let mut rng = StdRng::seed_from_u64(game);
+                solver::play(answer, 6, |candidates| candidates.choose(&mut rng).copied())
+            }
+        }
+    }
+}
+
+// shorthands for the flags used most, taking the flags for the game before them
+#[derive(Clone, Subcommand)]
+enum Command {
+    /// Play a game straight away, rather than picking one from the menu
+    Play,
+    /// Play today's puzzle, like --daily
+    Daily,
+    /// Print your statistics, like --stats
+    Stats,
+    /// Browse the games played so far, like --history
+    History,
+    /// Print the guesses the solver makes to find an answer
+    Solve { answer: String },
+    /// Let the solver guess a word you think of, like --reverse
+    Reverse,
+    /// Play a solver strategy against the answers and report how it does
+    Simulate {
+        #[arg(long, value_enum, default_value_t)]
+        strategy: Strategy,
+        /// Play this many answers picked at random, rather than all of them
+        #[arg(long, value_name = "N")]
+        games: Option<usize>,
+    },
+    /// Download the latest answer and guess lists into the data directory,
+    /// where they take the place of the built-in ones
+    UpdateWords,
+    /// Wait for a friend to join on this port, then race them to the same answer
+    Host { port: u16 },
+    /// Join the race a friend is hosting at this address, such as `192.168.1.20:7777`
+    Join { addr: String },
+    /// Write every recorded game to a file, or to stdout without one or for `-`
+    Export {
+        #[arg(long, value_enum, default_value_t)]
+        format: ExportFormat,
+        file: Option<PathBuf>,
+    },
+}
+
+#[derive(Parser)]
+#[command(
+    args_override_self = true,
+    after_help = "Flags can also be set in the WORDLE_OPTS environment variable, \
+separated by spaces. Flags given on the command line take precedence over it."
+)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// How guesses are scored
+    #[arg(long, value_enum, default_value_t)]
+    mode: Mode,
+
+    /// Show the time taken so far beside the grid, and after a win how it
+    /// compares to your fastest
+    #[arg(long)]
+    speedrun: bool,
+
+    /// Give every row a time limit in seconds, burning the row when it runs out
+    #[arg(long, value_name = "SECS")]
+    row_timer: Option<u64>,
+
+    /// Print your statistics and exit
+    #[arg(long)]
+    stats: bool,
+
+    /// Browse the games played so far and step through any of them again
+    #[arg(long)]
+    history: bool,
+
+    /// Think of a word and let the solver guess it, giving the clues for each of
+    /// its guesses
+    #[arg(long)]
+    reverse: bool,
+
+    /// Run the solver against every answer and report the ones it finds hardest
+    #[arg(long)]
+    audit_list: bool,
+
+    /// Score every valid guess as an opener against all answers, print the best ones and exit
+    #[arg(long)]
+    best_openers: bool,
+
+    /// Old spelling of `wordle export FILE`, kept for scripts using it
+    #[arg(long, value_name = "FILE", hide = true)]
+    export_stats: Option<PathBuf>,
+
+    /// Old spelling of `wordle export --format`
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        requires = "export_stats",
+        hide = true
+    )]
+    export_format: ExportFormat,
+
+    /// Print a keyboard coloring each letter by the clue it most often got, and exit
+    #[arg(long)]
+    heatmap: bool,
+
+    /// Write how often each letter came up green, yellow and grey to a CSV file and exit
+    #[arg(long, value_name = "FILE")]
+    heatmap_csv: Option<PathBuf>,
+
+    /// Hide the letters of the current guess until it is submitted
+    #[arg(long)]
+    blind: bool,
+
+    /// Pick the answer with a seeded RNG so the game can be replayed
+    #[arg(long, global = true)]
+    seed: Option<u64>,
+
+    /// Play against a specific answer
+    #[arg(long, conflicts_with = "seed")]
+    word: Option<String>,
+
+    /// Play the guesses listed in a file, one per line, without the TUI
+    #[arg(long, value_name = "FILE")]
+    guesses_from: Option<PathBuf>,
+
+    /// Replay a shared emoji grid, guessing words for it when the answer is given
+    /// by --word, --seed or --daily
+    #[arg(long, value_name = "FILE")]
+    replay_grid: Option<PathBuf>,
+
+    /// Check that the comma-separated guesses win the game picked by --seed, print
+    /// the verdict and exit, failing if they don't
+    #[arg(long, value_name = "GUESSES", value_delimiter = ',', requires = "seed")]
+    verify: Option<Vec<String>>,
+
+    /// Play guesses read from stdin, one per line, without the TUI
+    #[arg(long)]
+    plain: bool,
+
+    /// Like --plain, but only print the feedback for each guess, such as `GYBBB`,
+    /// or `REJECTED` with the reason, so that a script can play against the game
+    #[arg(long, conflicts_with_all = ["json", "share"])]
+    headless: bool,
+
+    /// Compare two games of the same puzzle, as logged by --json, side by side and exit
+    #[arg(long, num_args = 2, value_names = ["FILE", "FILE"])]
+    compare: Option<Vec<PathBuf>>,
+
+    /// Without the TUI, play this many games in a row, then print how they went
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    games: u64,
+
+    /// Play without the TUI a line at a time, with every clue spelled out in
+    /// words and nothing ever redrawn, for screen readers and braille terminals
+    #[arg(long, conflicts_with_all = ["plain", "headless", "json"])]
+    accessible: bool,
+
+    /// Without the TUI, print the whole game as one JSON object at the end
+    /// instead of a line per guess
+    #[arg(long)]
+    json: bool,
+
+    /// Ring the terminal bell when a guess is rejected and when the game is won or lost
+    #[arg(long)]
+    bell: bool,
+
+    /// Turn off animations such as shaking rejected guesses
+    #[arg(long)]
+    no_animation: bool,
+
+    /// Color theme, on top of which the config file can override single colors
+    #[arg(long, global = true, value_enum)]
+    theme: Option<ThemeName>,
+
+    /// Colors the terminal can show, instead of guessing from TERM and COLORTERM
+    #[arg(long, global = true, value_enum, value_name = "COLORS")]
+    colors: Option<ColorSupport>,
+
+    /// Lines the grid is drawn with [default: double]
+    #[arg(long, value_enum)]
+    border: Option<BorderStyle>,
+
+    /// Print the answers that were still possible before the final guess
+    #[arg(long)]
+    show_candidates: bool,
+
+    /// Keep changing the answer to dodge your guesses for as long as possible
+    #[arg(long, conflicts_with_all = ["seed", "word", "theme_words"])]
+    absurdle: bool,
+
+    /// Play this many boards at once, each guess going to all of them, with an
+    /// extra guess for every board. Not counted in the stats
+    #[arg(
+        long,
+        value_name = "COUNT",
+        value_parser = clap::value_parser!(u8).range(2..=4),
+        conflicts_with_all = ["word", "theme_words", "absurdle", "daily", "friend", "resume", "review"]
+    )]
+    boards: Option<u8>,
+
+    /// Number of guesses allowed before the game is lost
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 6,
+        value_parser = clap::value_parser!(u8).range(1..=12),
+        conflicts_with = "boards"
+    )]
+    max_guesses: u8,
+
+    /// Practice the answers you lost to or gave up on, oldest first, until each one is solved
+    #[arg(
+        long,
+        conflicts_with_all = ["seed", "word", "theme_words", "absurdle", "daily", "friend"]
+    )]
+    review: bool,
+
+    /// Play today's puzzle, the same for everyone
+    #[arg(long, conflicts_with_all = ["seed", "word", "theme_words", "absurdle"])]
+    daily: bool,
+
+    /// Shuffle the daily puzzles into a private order shared by everyone using the same salt
+    #[arg(long, value_name = "SALT", requires = "daily")]
+    daily_salt: Option<String>,
+
+    /// Take the daily answer from a file of tab-separated dates and answers, on
+    /// the days it lists
+    #[arg(long = "schedule", value_name = "FILE", requires = "daily")]
+    schedule_file: Option<PathBuf>,
+
+    /// The schedule read from `schedule_file`, once the word lists are settled.
+    #[arg(skip)]
+    schedule: Option<Schedule>,
+
+    /// Play with words of this many letters, from lists of their own
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 5,
+        value_parser = clap::value_parser!(u8).range(4..=8),
+        conflicts_with = "daily"
+    )]
+    length: u8,
+
+    /// Play with words in this language instead of English
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        conflicts_with_all = ["daily", "length", "difficulty"]
+    )]
+    lang: Language,
+
+    /// Pick answers from this file of words, one per line, instead of the usual list
+    #[arg(long, global = true, value_name = "FILE")]
+    answers: Option<PathBuf>,
+
+    /// Accept the guesses in this file of words, one per line, instead of the usual list
+    #[arg(long, global = true, value_name = "FILE")]
+    guesses: Option<PathBuf>,
+
+    /// Only pick answers from one category of words, such as `animal`
+    #[arg(long, value_name = "TAG", conflicts_with = "word")]
+    theme_words: Option<String>,
+
+    /// Pick more common or rarer answers than usual
+    #[arg(long, value_enum, default_value_t, conflicts_with = "word")]
+    difficulty: Difficulty,
+
+    /// Let rude and hurtful words be picked as answers, which are left out
+    /// otherwise though they can always be guessed
+    #[arg(long)]
+    allow_rude: bool,
+
+    /// After winning, keep guessing to explore the feedback without it counting
+    #[arg(long)]
+    keep_going: bool,
+
+    /// Play answer after answer on one shared budget of guesses, each one
+    /// solved scoring points and refilling the budget, until it runs out
+    #[arg(
+        long,
+        conflicts_with_all = ["seed", "word", "daily", "absurdle", "review", "boards", "friend", "resume", "max_guesses", "keep_going", "versus_bot", "deterministic", "tutorial"]
+    )]
+    marathon: bool,
+
+    /// Submit a guess as soon as its fifth letter makes it a valid word
+    #[arg(long)]
+    autosubmit: bool,
+
+    /// Say at the start of each game how many different letters the answer has
+    #[arg(long, conflicts_with = "absurdle")]
+    show_dupes: bool,
+
+    /// Color the current guess red once it can no longer become a valid word
+    #[arg(long)]
+    check_prefix: bool,
+
+    /// After a loss, walk through how your closest guess relates to the answer
+    #[arg(long)]
+    coach: bool,
+
+    /// When the game ends, write the emoji result grid to a file, replacing it
+    #[arg(long, value_name = "FILE")]
+    share_to: Option<PathBuf>,
+
+    /// Keep a copy of the board in this file or FIFO, updated after every guess,
+    /// for showing the game in another pane or a stream overlay
+    #[arg(long, value_name = "PATH")]
+    mirror: Option<PathBuf>,
+
+    /// Write the copy kept by --mirror with ANSI colors instead of as plain text
+    #[arg(long, requires = "mirror")]
+    mirror_color: bool,
+
+    /// Run this shell command for every guess, rejected guess and game end,
+    /// with the event as a line of JSON on its standard input, for desktop
+    /// notifications or passing the game on to other tools
+    #[arg(long, value_name = "COMMAND")]
+    event_hook: Option<String>,
+
+    /// When the game ends, print the emoji result grid to paste into a chat
+    #[arg(long)]
+    share: bool,
+
+    /// When the game ends, copy the emoji result grid to the clipboard through the terminal
+    #[arg(long)]
+    copy: bool,
+
+    /// Draw the shared grid with `#`, `+` and `.` instead of emoji squares
+    #[arg(long)]
+    share_ascii: bool,
+
+    /// Draw with plain ASCII only, for terminals or fonts that mangle box drawing
+    /// and emoji. Picked on its own when the locale isn't UTF-8
+    #[arg(long)]
+    ascii: bool,
+
+    /// Most hints that can be taken in a game
+    #[arg(long, value_name = "N")]
+    hint_budget: Option<usize>,
+
+    /// Only accept guesses that could themselves be answers
+    #[arg(long)]
+    answers_only: bool,
+
+    /// Make every guess use the greens and yellows found so far
+    #[arg(long, global = true)]
+    hard: bool,
+
+    /// Hard mode that also refuses grey letters, and yellow letters where they
+    /// were found
+    #[arg(long, global = true)]
+    ultra_hard: bool,
+
+    /// Refuse guesses using any of these letters
+    #[arg(long, value_name = "LETTERS", value_parser = parse_letters)]
+    ban: Option<BTreeSet<char>>,
+
+    /// Only accept everyday words as guesses, leaving out the obscure ones
+    #[arg(long, conflicts_with = "answers_only")]
+    common_only: bool,
+
+    /// Ask for a second submit before guessing a word with a letter already ruled out
+    #[arg(long)]
+    warn_absent: bool,
+
+    /// Faintly show the letters already pinned down in the empty cells of the current row
+    #[arg(long)]
+    ghosts: bool,
+
+    /// Color letters of the current guess as they are typed, green where an
+    /// earlier guess found them in that spot and grey where they aren't in the word
+    #[arg(long)]
+    input_hints: bool,
+
+    /// Let the candidates key toggle a panel of the answers still possible
+    #[arg(long)]
+    candidates: bool,
+
+    /// Let the assist key toggle a panel of the answers left and the guesses
+    /// expected to narrow them down the most
+    #[arg(long)]
+    assist: bool,
+
+    /// Let the frequency key toggle a panel of how many of the answers still
+    /// possible each letter is in
+    #[arg(long)]
+    letter_frequency: bool,
+
+    /// Show, beside each guess, how many answers were still possible after it
+    #[arg(long)]
+    analyze_live: bool,
+
+    /// Practice deduction: keep count of the answers left after each guess, and
+    /// list them beside the grid once only a few remain. The undo key takes back
+    /// the last guess, leaving the game out of the win rate and streaks
+    #[arg(long, conflicts_with = "assist")]
+    practice: bool,
+
+    /// Play the guided game new players start with, which explains the clues
+    /// of every guess beside the grid
+    #[arg(
+        long,
+        conflicts_with_all = ["seed", "word", "daily", "absurdle", "review", "boards", "friend", "resume", "mode", "candidates", "assist", "letter_frequency", "practice"]
+    )]
+    tutorial: bool,
+
+    /// Number the rows, coloring each guess's number by how many of its letters are in the answer
+    #[arg(long)]
+    annotate: bool,
+
+    /// Tint the background of each guess by how close it came to the answer
+    #[arg(long)]
+    warmth: bool,
+
+    /// Play the same game every time, from --seed or a fixed one, with no timers,
+    /// nothing that depends on your stats and nothing added to them
+    #[arg(long)]
+    deterministic: bool,
+
+    /// After the game, print how many answers each guess left, the best guess
+    /// there was, and scores for skill and luck
+    #[arg(long)]
+    analysis: bool,
+
+    /// After a lost or abandoned game, step through what the solver would have
+    /// guessed from your opener
+    #[arg(long)]
+    coach_walkthrough: bool,
+
+    /// Briefly flash the tiles of each guess that turned up something new
+    #[arg(long)]
+    highlight_new: bool,
+
+    /// Dim the guesses already made so that the row being typed in stands out
+    #[arg(long)]
+    focus: bool,
+
+    /// Start every guess with the answer's first letter already filled in, for young players
+    #[arg(long, conflicts_with = "absurdle")]
+    easy_start: bool,
+
+    /// Show a keyboard below the grid with every letter colored by what it turned up
+    #[arg(long)]
+    keyboard: bool,
+
+    /// Show the keyboard, also marking under each yellow letter the positions it is ruled out of
+    #[arg(long)]
+    advanced_keyboard: bool,
+
+    /// Show the keyboard with buttons to submit and erase, and type by clicking on it
+    #[arg(long)]
+    mouse: bool,
+
+    /// Layout of the keyboard shown with --keyboard and --heatmap [default: qwerty, or azerty for --lang fr]
+    #[arg(long, value_enum)]
+    layout: Option<KeyboardLayout>,
+
+    /// Have a friend secretly type in the answer before every game
+    #[arg(
+        long,
+        conflicts_with_all = ["seed", "word", "theme_words", "absurdle", "daily", "deterministic"]
+    )]
+    friend: bool,
+
+    /// Who has picked and solved what so far with `--friend`.
+    #[arg(skip)]
+    hot_seat: HotSeat,
+
+    /// Save the game when quitting before it is over, and pick up a saved game
+    /// where it was left
+    #[arg(long, conflicts_with = "absurdle")]
+    resume: bool,
+
+    /// Race the solver to the answer, its clues showing beside yours as it
+    /// guesses, the fewest guesses winning and the quicker player breaking ties
+    #[arg(long, conflicts_with = "absurdle")]
+    versus_bot: bool,
+
+    /// Retry drawing the screen when writing to the terminal fails for a moment,
+    /// as it can over a flaky connection, instead of ending the game
+    #[arg(long)]
+    soft_fail: bool,
+}
+
+/// Most answers one letter away from the answer that the coach lists.
+const COACH_NEIGHBORS: usize = 8;
+
+/// Times a batch game is picked again to avoid an answer already played.
+const BATCH_RETRIES: u64 = 100;
+
+/// Exit code after Ctrl-C, 128 plus the number of SIGINT.
+const INTERRUPTED: u8 = 130;
+
+/// Seed used by `--deterministic` when no other is given.
+const DEMO_SEED: u64 = 0;
+
+/// Times a frame is drawn again with `--soft-fail` before giving up on it.
+const RENDER_RETRIES: u32 = 3;
+
+/// How long a toast, such as why a guess was rejected, stays up.
+const TOAST_TIME: Duration = Duration::from_millis(1500);
+
+/// Time between turning over each tile of a guess.
+const REVEAL_DELAY: Duration = Duration::from_millis(120);
+
+/// How long the tiles of a guess that turned up something new flash for.
+const FRESH_TIME: Duration = Duration::from_millis(400);
+
+/// How often the game wakes up while something on screen keeps moving, such
+/// as the row timer or the other side of a race.
+const TICK: Duration = Duration::from_millis(100);
+
+/// Least time between frames while keys are still queued up, so that a burst
+/// of them is drawn once rather than after every one.
+const FRAME_TIME: Duration = Duration::from_millis(16);
+
+/// Time the bot takes over each guess with `--versus-bot`.
+const BOT_PACE: Duration = Duration::from_secs(8);
+
+/// The answer of the tutorial game, the same for everyone.
+const TUTORIAL_ANSWER: &str = "ghost";
+
+/// Answers left at which `--practice` starts listing them.
+const PRACTICE_LIST: usize = 10;
+
+/// Boards played at once when picked from the main menu.
+const MENU_BOARDS: u8 = 4;
+
+/// Guesses suggested by the assist panel.
+const ASSIST_SUGGESTIONS: usize = 5;
+
+/// Players listed from the leaderboard when a daily game ends.
+#[cfg(feature = "online-leaderboard")]
+const LEADERBOARD_ROWS: usize = 5;
+
+/// Submits closer together than this are taken as the same keypress.
+const SUBMIT_DEBOUNCE: Duration = Duration::from_millis(150);
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Everything the program does, anything that stops it before it is done
+/// coming back as an error for `main` to report.
+fn run() -> Result<ExitCode, WordleError> {
+    // up front, so that a missing or broken list is reported before anything else
+    #[cfg(feature = "runtime-wordlists")]
+    load_wordlists()?;
+
+    let mut args = parse_args();
+    let config = Config::load()?;
+    apply_config(&mut args, &config);
+
+    // before anything looks at the lists, which fixes them for good, except
+    // when downloading new ones, which is how broken ones get fixed
+    if !matches!(args.command, Some(Command::UpdateWords)) {
+        wordle::use_length(args.length.into());
+        wordle::use_language(args.lang.code());
+        use_custom_lists(&args)?;
+    }
+
+    match args.command.clone() {
+        Some(Command::Daily) => {
+            // everyone's daily word is one of the five-letter English answers
+            let changed = [
+                ("--length", args.length != 5),
+                ("--lang", args.lang != Language::English),
+            ];
+            if let Some((flag, _)) = changed.into_iter().find(|&(_, given)| given) {
+                Args::command()
+                    .error(
+                        ErrorKind::ArgumentConflict,
+                        format!("{flag} can't be used with the daily puzzle"),
+                    )
+                    .exit();
+            }
+
+            args.daily = true;
+        }
+        Some(Command::Stats) => args.stats = true,
+        Some(Command::History) => args.history = true,
+        Some(Command::Reverse) => args.reverse = true,
+        Some(Command::Solve { answer }) => return Ok(solve(&answer)),
+        Some(Command::Simulate { strategy, games }) => {
+            return Ok(simulate(strategy, games, args.seed.unwrap_or(DEMO_SEED)))
+        }
+        Some(Command::UpdateWords) => return Ok(update_words(&config)),
+        Some(Command::Export { format, file }) => {
+            export_stats(format, file.filter(|file| file.as_os_str() != "-"))?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        Some(Command::Host { .. } | Command::Join { .. }) => {
+            if let Some(flag) = race_conflict(&args) {
+                Args::command()
+                    .error(
+                        ErrorKind::ArgumentConflict,
+                        format!("{flag} can't be used in a race"),
+                    )
+                    .exit();
+            }
+        }
+        Some(Command::Play) | None => {}
+    }
+
+    if let Some(path) = &args.schedule_file {
+        match read_schedule(path) {
+            Ok(schedule) => args.schedule = Some(schedule),
+            Err(err) => {
+                eprintln!("{err}");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+
+    if args.deterministic {
+        make_deterministic(&mut args);
+    }
+
+    if args.stats {
+        print!("{}", Stats::load()?.report());
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if args.audit_list {
+        audit_list(&ANSWERS);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if args.best_openers {
+        best_openers();
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(path) = &args.export_stats {
+        let file = Some(path.clone()).filter(|file| file.as_os_str() != "-");
+        export_stats(args.export_format, file)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if args.heatmap {
+        let theme = load_theme(&args, &config);
+        print_heatmap(&Stats::load()?, &theme, args.layout.unwrap_or_default());
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(path) = &args.heatmap_csv {
+        Stats::load()?.export_heatmap_csv(path)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(word) = &args.word {
+        let length = wordle::word_length();
+        if letter_count(word) != length || !wordle::is_list_word(&word.to_lowercase()) {
+            eprintln!("The answer must be a {length}-letter word, got `{word}`");
+            return Ok(ExitCode::FAILURE);
+        }
+
+        // otherwise the game could never be won
+        if word_list(&args) != WordList::Full
+            && !new_game(&args).dictionary().contains(&word.to_lowercase())
+        {
+            eprintln!("`{word}` isn't allowed as a guess, so the game could never be won");
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    if args.review && review_word().is_none() {
+        eprintln!("No missed words to review");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    if args.common_only {
+        if let Some(word) = uncommon_common_word() {
+            eprintln!("The common word list has `{word}`, which isn't a valid guess");
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    if let Some(tag) = &args.theme_words {
+        if words_in_category(tag).is_empty() {
+            let tags: Vec<_> = categories().into_iter().collect();
+            eprintln!(
+                "No answers are tagged `{tag}`, try one of: {}",
+                tags.join(", ")
+            );
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    if picks_answer(&args) && answer_pool(&args).is_empty() {
+        if args.ban.is_some() {
+            eprintln!("Every answer has a banned letter");
+        } else {
+            eprintln!("No answer is that difficulty");
+        }
+
+        return Ok(ExitCode::FAILURE);
+    }
+
+    if let Some(path) = &args.replay_grid {
+        let theme = load_theme(&args, &config);
+        replay_grid(&args, &theme, &std::fs::read_to_string(path)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if args.daily {
+        let day = new_game(&args).day;
+
+        if day.is_some_and(|day| Stats::load().is_ok_and(|stats| stats.played_daily(day))) {
+            println!(
+                "You've already played today's puzzle, the next one is in {}",
+                stats::fmt_duration(until_next_daily())
+            );
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    if let Some(guesses) = &args.verify {
+        return Ok(verify(&args, guesses));
+    }
+
+    if let Some(paths) = &args.compare {
+        return Ok(compare_logs(paths));
+    }
+
+    if let Some(path) = &args.guesses_from {
+        let file = std::fs::File::open(path).map_err(|source| WordleError::File {
+            path: path.clone(),
+            source,
+        })?;
+        return Ok(play_batch(&args, BufReader::new(file))?);
+    }
+
+    if args.plain || args.json || args.headless {
+        return Ok(play_batch(&args, std::io::stdin().lock())?);
+    }
+
+    if args.accessible {
+        return Ok(play_accessible(&args)?);
+    }
+
+    let mut race = None;
+
+    if let Some(Command::Host { port }) = args.command {
+        let seed = *args.seed.get_or_insert_with(rand::random);
+        println!("Waiting for a friend to join with `wordle join <this address>:{port}`");
+
+        match Race::host(port, seed) {
+            Ok(hosted) => race = Some(hosted),
+            Err(err) => {
+                eprintln!("Couldn't host a race on port {port}: {err}");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+
+    if let Some(Command::Join { addr }) = &args.command {
+        match Race::join(addr) {
+            Ok((joined, seed)) => {
+                args.seed = Some(seed);
+                race = Some(joined);
+            }
+            Err(err) => {
+                eprintln!("Couldn't join the race at {addr}: {err}");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+    }
+
+    let mut theme = load_theme(&args, &config);
+
+    std::panic::set_hook(Box::new(|info| {
+        guard::restore();
+        println!("thread {info}");
+    }));
+
+    let mut stdout = std::io::stdout();
+    check_terminal()?;
+    let terminal_guard = TerminalGuard::new()?;
+
+    if !wait_for_size(&args, &config)? {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    args.tutorial |= first_launch(&args, &config);
+
+    // nothing at all on the command line opens the menu, to pick a game from,
+    // other than the tutorial for new players
+    if std::env::args_os().len() == 1 && !args.tutorial {
+        if !main_menu(&mut args, &config)? || !wait_for_size(&args, &config)? {
+            return Ok(ExitCode::SUCCESS);
+        }
+
+        theme = load_theme(&args, &config);
+    }
+
+    if args.history {
+        let stats = Stats::load()?;
+        history(&stats, &args, &theme, &config)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if args.reverse {
+        let found = reverse(&theme, &config)?;
+        drop(terminal_guard);
+        return Ok(if found {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
+
+    if let Some(count) = args.boards {
+        return Ok(play_boards(
+            &args,
+            usize::from(count),
+            &theme,
+            &config,
+            terminal_guard,
+        )?);
+    }
+
+    let resumed = if args.resume {
+        resume_game(&args)
+    } else {
+        Ok(None)
+    };
+
+    if args.friend && !matches!(resumed, Ok(Some(_))) && !secret_word(&mut args, &theme, &config)? {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut session = Session::new(args, &theme, &config, race, resumed);
+
+    if session.args.mouse {
+        execute!(stdout, EnableMouseCapture)?;
+    }
+
+    let mut scene = Scene::Playing;
+
+    let (outcome, duration) = loop {
+        scene = match scene {
+            Scene::Playing => session.play()?,
+            Scene::Over(outcome, duration) => session.game_over(outcome, duration)?,
+            Scene::End(ending) => session.end_menu(ending)?,
+            Scene::Stats(ending) => session.stats(ending)?,
+            Scene::Done(outcome, duration) => break (outcome, duration),
+        };
+    };
+
+    session.leave(outcome, duration, terminal_guard)
+}
+
+/// The screens of a game played in the terminal, `run` going from one to the
+/// next until the player is done.
+enum Scene {
+    /// The game being played.
+    Playing,
+    /// A game that just ended this way after this long, to be recorded before
+    /// anything else is shown.
+    Over(Outcome, Duration),
+    /// The menu after a game, for what to do next.
+    End(Ending),
+    /// The stats, looked at from the end menu, which they go back to.
+    Stats(Ending),
+    /// Back to the shell, after the last game ended this way after this long.
+    Done(Outcome, Duration),
+}
+
+/// A recorded game, as the end menu goes over it.
+struct Ending {
+    outcome: Outcome,
+    duration: Duration,
+    notes: Vec<String>,
+    /// The choice the menu is on, still there when the stats go back to it.
+    selected: usize,
+}
+
+/// What came of waiting on the player in the middle of a game.
+enum Input {
+    Key(KeyCode),
+    /// Nothing to act on, only something to draw again.
+    Redraw,
+    /// The game was left, with Ctrl-C or by the board no longer fitting.
+    Left(Outcome),
+}
+
+/// Everything a game played in the terminal keeps track of from one screen to
+/// the next, and on into the games after it.
+struct Session<'a> {
+    args: Args,
+    theme: &'a Theme,
+    config: &'a Config,
+    screen: Screen,
+    race: Option<Race>,
+    recorder: StatsRecorder,
+    observers: Observers,
+    /// Where --mirror sends the board.
+    mirror: Option<Mirror>,
+    /// What the mirror was last sent.
+    mirrored: String,
+    wordle: Wordle,
+    /// The game as it stood when it was won, while --keep-going lets guessing
+    /// carry on.
+    explored: Option<Wordle>,
+    /// The run a --marathon game is part of, the budget making up its rows.
+    marathon: Option<Marathon>,
+    hud: Hud,
+    row_clock: Stopwatch,
+    /// Paused along with the row timer, so a pause doesn't count as time played.
+    game_clock: Stopwatch,
+    /// When Enter was last pressed, so that a double tap or held key submits
+    /// only once.
+    last_submit: Option<Instant>,
+    /// When the last guess started being turned over, while it still is, and
+    /// how many guesses there were then.
+    reveal: Option<(Instant, usize)>,
+    last_frame: Option<Instant>,
+    /// The last guess warned about for using a letter already ruled out.
+    warned: Option<String>,
+    /// Whether the game was already recorded and looked back on from the end menu.
+    finished: bool,
+    /// Whether the game was left with Ctrl-C.
+    interrupted: bool,
+    /// The answer and number of guesses last autosaved with --resume.
+    autosaved: Option<(String, usize)>,
+}
+
+impl<'a> Session<'a> {
+    /// Sets up the first game, the one `resumed` if it could be.
+    fn new(
+        args: Args,
+        theme: &'a Theme,
+        config: &'a Config,
+        race: Option<Race>,
+        resumed: Result<Option<Wordle>, String>,
+    ) -> Self {
+        let mut wordle = match &resumed {
+            Ok(Some(wordle)) => wordle.clone(),
+            _ => new_game(&args),
+        };
+
+        let marathon = args.marathon.then(Marathon::new);
+
+        if let Some(run) = &marathon {
+            run.start(&mut wordle);
+        }
+
+        let stats = Stats::load().ok();
+        let mut hud = Hud {
+            status: (!config.hide_status_bar).then(|| GameContext {
+                mode: mode_name(&args, &wordle),
+                streak: stats.as_ref().map_or(0, Stats::current_streak),
+                elapsed: Duration::ZERO,
+            }),
+            tip: !config.hide_tips
+                && !args.deterministic
+                && !args.tutorial
+                && stats.as_ref().is_some_and(|stats| stats.games.is_empty()),
+            streak: stats
+                .zip(wordle.day)
+                .filter(|(stats, day)| !config.hide_streak && !stats.played_daily(*day))
+                .map(|(stats, day)| stats.daily_streak(day - 1))
+                .filter(|&streak| streak > 0),
+            marathon,
+            ..Hud::default()
+        };
+
+        if args.show_dupes {
+            hud.message = Some(dupes_message(&wordle));
+        }
+
+        if answer_is_banned(&args, &wordle) {
+            hud.message = Some(String::from(
+                "The answer has a banned letter, so this game can't be won",
+            ));
+        }
+
+        if race.is_some() {
+            hud.opponent = Some(Opponent::default());
+        }
+
+        if args.versus_bot {
+            hud.opponent = Some(Opponent::bot(&wordle));
+        }
+
+        if let Err(err) = resumed {
+            hud.message = Some(format!("Couldn't resume: {err}"));
+        } else if !args.resume && SavedGame::exists() {
+            hud.message = Some(String::from("A game was saved, pick it up with --resume"));
+        }
+
+        // the words of a marathon aren't games of their own, only the run is kept
+        let recorder = StatsRecorder::default();
+        let observers = game_observers(&args, (!args.marathon).then_some(&recorder));
+
+        Self {
+            mirror: args.mirror.clone().map(Mirror::new),
+            args,
+            theme,
+            config,
+            screen: Screen::default(),
+            race,
+            recorder,
+            observers,
+            mirrored: String::new(),
+            wordle,
+            explored: None,
+            marathon,
+            hud,
+            row_clock: Stopwatch::start(),
+            game_clock: Stopwatch::start(),
+            last_submit: None,
+            reveal: None,
+            last_frame: None,
+            warned: None,
+            finished: false,
+            interrupted: false,
+            autosaved: None,
+        }
+    }
+
+    /// Starts the next game, in the marathon there is one of.
+    fn next_game(&mut self) {
+        self.args.tutorial = false;
+        self.wordle = new_game(&self.args);
+
+        if let Some(run) = &self.marathon {
+            run.start(&mut self.wordle);
+        }
+
+        self.hud = game_hud(&self.args, &self.wordle, self.config);
+        self.hud.marathon = self.marathon;
+        self.row_clock = Stopwatch::start();
+        self.game_clock = Stopwatch::start();
+    }
+
+    /// Plays the game until it is over or left.
+    fn play(&mut self) -> Result<Scene, WordleError> {
+        let outcome = loop {
+            let row_time = self
+                .args
+                .row_timer
+                .map(Duration::from_secs)
+                .filter(|_| self.explored.is_none());
+
+            self.refresh(row_time);
+
+            // keys that came in faster than frames are drawn, such as a held
+            // key repeating, are caught up on before the next one
+            let behind = self
+                .last_frame
+                .is_some_and(|last| last.elapsed() < FRAME_TIME)
+                && self.wordle.won().is_none()
+                && event::poll(Duration::ZERO)?;
+
+            if !behind {
+                draw_retrying(&self.args, &mut self.screen, |screen| {
+                    render_wordle(screen, &self.wordle, &self.args, self.theme, &self.hud)
+                })?;
+                self.last_frame = Some(Instant::now());
+            }
+
+            match self.wordle.won() {
+                _ if self.explored.is_some() => {}
+
+                // the last guess is shown in full before the game ends
+                _ if self.reveal.is_some() || self.hud.fresh.is_some() => {}
+
+                Some(true) if self.args.keep_going => {
+                    send_done(&mut self.race, true);
+                    ring_bell(&self.args, 2)?;
+                    self.explored = Some(self.wordle.clone());
+                    self.hud.message = Some(String::from(
+                        "EXPLORING: guesses no longer count, quit to finish",
+                    ));
+                    continue;
+                }
+
+                Some(won) => {
+                    send_done(&mut self.race, won);
+                    ring_bell(&self.args, if won { 2 } else { 3 })?;
+                    std::thread::sleep(Duration::from_secs(1));
+                    break if won { Outcome::Won } else { Outcome::Lost };
+                }
+
+                None => {}
+            }
+
+            let timed = row_time.filter(|_| !self.row_clock.is_paused());
+
+            if timed.is_some_and(|time| self.row_clock.elapsed() >= time) {
+                self.wordle.burn_guess();
+                self.hud.remaining = self.wordle.remaining_per_row();
+                self.row_clock = Stopwatch::start();
+                continue;
+            }
+
+            let code = match self.input(timed.is_some())? {
+                Input::Key(code) => code,
+                Input::Redraw => continue,
+                Input::Left(outcome) => break outcome,
+            };
+
+            self.hud.streak = None;
+
+            // any key dismisses the tip without doing anything else
+            if self.hud.tip {
+                self.hud.tip = false;
+                self.row_clock = Stopwatch::start();
+                continue;
+            }
+
+            // any key closes the help, which paused the game, without doing anything else
+            if self.hud.help.take().is_some() {
+                self.row_clock.resume();
+                self.game_clock.resume();
+                continue;
+            }
+
+            // any key resumes a paused game without doing anything else
+            if self.row_clock.is_paused() {
+                self.row_clock.resume();
+                self.game_clock.resume();
+                continue;
+            }
+
+            let mut action = self.config.keys.action(code);
+
+            if let (None, KeyCode::Char(c)) = (action, code) {
+                let before = self.wordle.curr.clone();
+                self.wordle.input(c);
+
+                // a word that isn't valid stays in the row to be fixed
+                match filled_row(&self.wordle, &before).filter(|_| self.args.autosubmit) {
+                    Some(true) => {
+                        action = Some(Action::Submit);
+                        self.last_submit = None;
+                    }
+                    Some(false) => {
+                        let why = rejection_message(&GuessResult::NotAWord).unwrap_or_default();
+                        self.hud.toast = Some((why, Instant::now() + TOAST_TIME));
+                    }
+                    None => {}
+                }
+            }
+
+            if let Some(outcome) = self.act(action)? {
+                break outcome;
+            }
+        };
+
+        let duration = self.game_clock.elapsed();
+
+        // quitting in the middle of a game leaves right away, without the end screen
+        Ok(if outcome == Outcome::Forfeit {
+            Scene::Done(outcome, duration)
+        } else {
+            Scene::Over(outcome, duration)
+        })
+    }
+
+    /// Catches up on everything that moves on by itself before the board is
+    /// drawn again: the save, the mirror, the other side of a race and what the
+    /// HUD shows, with rows `row_time` long if they are timed.
+    fn refresh(&mut self, row_time: Option<Duration>) {
+        let hud = &mut self.hud;
+
+        // saved after every guess, so that even a game that is killed can be picked up
+        let progress = Some((self.wordle.answer.clone(), self.wordle.guesses().len()));
+
+        if self.args.resume && self.explored.is_none() && self.autosaved != progress {
+            if let Err(err) = autosave(&self.wordle) {
+                hud.message = Some(format!("Couldn't save the game: {err}"));
+            }
+
+            self.autosaved = progress;
+        }
+
+        if let Some(mirror) = &self.mirror {
+            let snapshot = mirror_snapshot(&self.wordle, &self.args, self.theme);
+
+            if snapshot != self.mirrored {
+                mirror.show(snapshot.clone());
+                self.mirrored = snapshot;
+            }
+        }
+
+        if let (Some(race), Some(opponent)) = (&self.race, &mut hud.opponent) {
+            for event in race.events() {
+                match event {
+                    RaceEvent::Guess(clues) => opponent.rows.push(clues),
+                    RaceEvent::Done { won } => opponent.won = Some(won),
+                    RaceEvent::Left => opponent.left = true,
+                }
+            }
+        }
+
+        if let Some(opponent) = &mut hud.opponent {
+            opponent.catch_up(self.game_clock.elapsed());
+        }
+
+        hud.clock = self.args.speedrun.then(|| self.game_clock.elapsed());
+        if let Some(status) = &mut hud.status {
+            status.elapsed = self.game_clock.elapsed();
+        }
+        hud.next_daily = self.wordle.day.map(|_| until_next_daily());
+        hud.paused = self.row_clock.is_paused();
+        hud.toast = hud
+            .toast
+            .take()
+            .filter(|(_, until)| *until > Instant::now());
+
+        // the guess is turned over a tile at a time while keys are still
+        // taken, its new information flashing once it is all showing
+        self.reveal = self
+            .reveal
+            .filter(|&(_, row)| row == self.wordle.guesses().len());
+        hud.revealed = self
+            .reveal
+            .map(|(start, _)| (start.elapsed().as_millis() / REVEAL_DELAY.as_millis()) as usize)
+            .filter(|&revealed| revealed < self.wordle.length());
+
+        if self.reveal.is_some() && hud.revealed.is_none() {
+            self.reveal = None;
+
+            if self.args.highlight_new {
+                hud.fresh = Some((self.wordle.new_information(), Instant::now() + FRESH_TIME));
+            }
+        }
+
+        hud.fresh = hud
+            .fresh
+            .take()
+            .filter(|(_, until)| *until > Instant::now());
+        hud.timer = row_time.map(|time| {
+            let left = time.saturating_sub(self.row_clock.elapsed());
+            left.as_secs_f64() / time.as_secs_f64()
+        });
+    }
+
+    /// Waits for the next key, with the row timer running if `timed`, taking
+    /// care of everything else that can come in meanwhile.
+    fn input(&mut self, timed: bool) -> Result<Input, WordleError> {
+        let hud = &self.hud;
+
+        // wake up for whichever comes first of the next tile to turn over,
+        // a toast or flash to take down, the row timer, the other side of a
+        // race, the bot or the clock moving on, and the countdown to the next
+        // daily puzzle or time in the status bar, blocking on keys otherwise
+        let ticking = timed || self.race.is_some() || self.args.versus_bot || self.args.speedrun;
+        let counting = hud.next_daily.is_some() || hud.status.is_some();
+        let until = |when: Instant| when.saturating_duration_since(Instant::now());
+
+        let wake = [
+            self.reveal.map(|(start, _)| {
+                let turned = hud.revealed.unwrap_or(0) as u32 + 1;
+                until(start + REVEAL_DELAY * turned)
+            }),
+            hud.toast.as_ref().map(|(_, when)| until(*when)),
+            hud.fresh.as_ref().map(|(_, when)| until(*when)),
+            ticking.then_some(TICK),
+            counting.then_some(Duration::from_secs(1)),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+
+        if let Some(timeout) = wake {
+            if !event::poll(timeout)? {
+                return Ok(Input::Redraw);
+            }
+        }
+
+        Ok(Input::Key(match event::read()? {
+            // raw mode keeps Ctrl-Z from suspending on its own, so it is
+            // done by hand, coming back to the game paused
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('z'),
+                modifiers,
+                ..
+            }) if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.row_clock.pause();
+                self.game_clock.pause();
+                suspend(self.args.mouse)?;
+                self.screen.invalidate();
+                return Ok(Input::Redraw);
+            }
+
+            // nor does Ctrl-C interrupt it, so it quits the game the same way
+            // as the quit key, only exiting as an interrupted program would
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('c'),
+                modifiers,
+                ..
+            }) if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.interrupted = true;
+                return Ok(Input::Left(if self.explored.is_some() {
+                    Outcome::Won
+                } else {
+                    Outcome::Forfeit
+                }));
+            }
+
+            Event::Key(KeyEvent { code, .. }) => code,
+
+            // a pasted word goes into the row as though it were typed
+            Event::Paste(text) => {
+                let busy = hud.tip || hud.help.is_some() || self.row_clock.is_paused();
+
+                if !busy {
+                    self.wordle.paste(&text);
+                }
+
+                return Ok(Input::Redraw);
+            }
+
+            // a click on the keyboard stands in for pressing the key
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                ..
+            }) if self.args.mouse && self.args.mode == Mode::Classic => {
+                let (cols, _) = terminal::size()?;
+                let (_, y) = grid_origin(&self.args)?;
+                let layout = self.args.layout.unwrap_or_default();
+
+                let top = y + grid_height(self.wordle.max_guesses) + 3;
+
+                match keyboard_click(layout, cols, top, self.args.ascii, (column, row)) {
+                    Some(Click::Letter(c)) => KeyCode::Char(c),
+                    Some(Click::Submit) => self.config.keys.submit.0,
+                    Some(Click::Erase) => self.config.keys.erase.0,
+                    None => return Ok(Input::Redraw),
+                }
+            }
+
+            // the next frame is centered for the new size, once the board fits again
+            Event::Resize(..) => {
+                let paused = self.row_clock.is_paused();
+                self.row_clock.pause();
+                self.game_clock.pause();
+
+                let fits = wait_for_size(&self.args, self.config)?;
+                self.screen.invalidate();
+
+                if !paused {
+                    self.row_clock.resume();
+                    self.game_clock.resume();
+                }
+
+                if !fits {
+                    return Ok(Input::Left(Outcome::Forfeit));
+                }
+
+                return Ok(Input::Redraw);
+            }
+
+            _ => return Ok(Input::Redraw),
+        }))
+    }
+
+    /// Does what a key asked for in the middle of a game, giving how the game
+    /// ended if that ended it.
+    fn act(&mut self, action: Option<Action>) -> Result<Option<Outcome>, WordleError> {
+        let hud = &mut self.hud;
+        let wordle = &mut self.wordle;
+
+        match action {
+            Some(Action::Quit) if self.explored.is_some() => return Ok(Some(Outcome::Won)),
+            Some(Action::Quit) => return Ok(Some(Outcome::Forfeit)),
+
+            // both sides of a race have to stay on the same answer
+            Some(Action::Restart) if self.race.is_some() => {}
+
+            Some(Action::Restart) => {
+                // backing out of picking the next answer goes back to this game
+                if self.args.friend {
+                    let picked = secret_word(&mut self.args, self.theme, self.config)?;
+                    self.screen.invalidate();
+
+                    if !picked {
+                        return Ok(None);
+                    }
+                }
+
+                if let Some(game) = self.explored.take() {
+                    self.recorder
+                        .ending(&self.args, &game, self.game_clock.elapsed());
+                    self.observers.on_game_end(&game);
+                } else if !wordle.guesses().is_empty() {
+                    self.recorder
+                        .ending(&self.args, wordle, self.game_clock.elapsed());
+                    self.observers.on_game_end(wordle);
+                }
+
+                // starting over in a marathon gives up the whole run
+                if let Some(run) = &mut self.marathon {
+                    record_marathon(run);
+                    *run = Marathon::new();
+                }
+
+                self.next_game();
+            }
+
+            Some(Action::Category) if !wordle.can_hint(Hint::Category) => {
+                hud.message = Some(String::from("No hints left"));
+            }
+
+            Some(Action::Category) => {
+                hud.message = Some(match wordle.category_hint() {
+                    Some(category) => format!("Category: {category}"),
+                    None => String::from("No hint available"),
+                });
+            }
+
+            Some(Action::Anagram) if !wordle.can_hint(Hint::Anagram) => {
+                hud.message = Some(String::from("No hints left"));
+            }
+
+            Some(Action::Anagram) => {
+                hud.message = Some(match wordle.anagram_hint() {
+                    (0, _) => String::from("No other answer has the same letters"),
+                    (count, Some(anagram)) => {
+                        format!("Anagrams: {count}, like {}", anagram.to_uppercase())
+                    }
+                    (count, None) => format!("Anagrams: {count}"),
+                });
+            }
+
+            Some(Action::Hint) if !wordle.can_hint(Hint::Letter) => {
+                hud.message = Some(String::from("No hints left"));
+            }
+
+            Some(Action::Hint) => {
+                hud.message = Some(match wordle.letter_hint() {
+                    Some((idx, c)) => format!("Letter {} is {}", idx + 1, upper_letter(&c)),
+                    None => String::from("No hint available"),
+                });
+            }
+
+            Some(Action::Hard) if !wordle.guesses().is_empty() => {
+                hud.message = Some(String::from(
+                    "Hard mode can only be changed before the first guess",
+                ));
+            }
+
+            Some(Action::Hard) => {
+                let args = &mut self.args;
+                args.hard = !args.hard;
+                args.ultra_hard &= args.hard;
+                wordle.hard = args.hard;
+                wordle.ultra_hard = args.ultra_hard;
+                hud.message = Some(String::from(if args.hard {
+                    "Hard mode on"
+                } else {
+                    "Hard mode off"
+                }));
+            }
+
+            Some(Action::Pause) => {
+                self.row_clock.pause();
+                self.game_clock.pause();
+            }
+
+            Some(Action::Help) => {
+                self.row_clock.pause();
+                self.game_clock.pause();
+                hud.help = Some(
+                    self.config
+                        .keys
+                        .bindings()
+                        .map(|(key, action)| (key.name(), action.label()))
+                        .to_vec(),
+                );
+            }
+
+            Some(Action::Candidates) if self.args.candidates => {
+                hud.candidates = !hud.candidates;
+                hud.assist = None;
+                hud.frequency = false;
+            }
+
+            Some(Action::Candidates) => {}
+
+            // only for moving around menus
+            Some(Action::Up | Action::Down) => {}
+
+            // the panels go beside the grid, so only one is shown at a time
+            Some(Action::Assist) if self.args.assist && hud.assist.is_none() => {
+                hud.assist = Some(suggestions(wordle));
+                hud.candidates = false;
+                hud.frequency = false;
+            }
+
+            Some(Action::Assist) => {
+                hud.assist = None;
+            }
+
+            Some(Action::Frequency) if self.args.letter_frequency => {
+                hud.frequency = !hud.frequency;
+                hud.candidates = false;
+                hud.assist = None;
+            }
+
+            Some(Action::Frequency) => {}
+
+            Some(Action::Undo) if self.args.practice => {
+                hud.message = Some(match wordle.undo() {
+                    Some(guess) => {
+                        hud.remaining = wordle.remaining_per_row();
+                        format!(
+                            "Took back {}, this game is now unranked",
+                            guess.to_uppercase()
+                        )
+                    }
+                    None => String::from("No guess to take back"),
+                });
+            }
+
+            Some(Action::Undo) => {}
+
+            Some(Action::Complete) => {
+                wordle.complete();
+            }
+
+            Some(Action::Erase) => {
+                wordle.erase();
+            }
+
+            Some(Action::Submit)
+                if self
+                    .last_submit
+                    .is_some_and(|at| at.elapsed() < SUBMIT_DEBOUNCE) =>
+            {
+                self.last_submit = Some(Instant::now());
+            }
+
+            Some(Action::Submit) => {
+                self.last_submit = Some(Instant::now());
+                self.submit()?;
+            }
+
+            None => {}
+        }
+
+        Ok(None)
+    }
+
+    /// Guesses the word typed into the row.
+    fn submit(&mut self) -> Result<(), WordleError> {
+        let args = &self.args;
+        let hud = &mut self.hud;
+        let wordle = &mut self.wordle;
+
+        // a guess with a letter already ruled out goes through on the second try
+        if args.warn_absent
+            && args.mode == Mode::Classic
+            && self.warned.as_ref() != Some(&wordle.curr)
+            && wordle.dictionary().contains(&wordle.curr)
+        {
+            if let Some(c) = wordle.ruled_out_letter() {
+                let why = format!(
+                    "{} isn't in the word, submit again to guess anyway",
+                    upper(c)
+                );
+                hud.toast = Some((why, Instant::now() + TOAST_TIME));
+                self.warned = Some(wordle.curr.clone());
+                return Ok(());
+            }
+        }
+
+        // scroll the board once it is full rather than running out of rows
+        let oldest = (self.explored.is_some() && wordle.guesses().len() == wordle.max_guesses)
+            .then(|| wordle.drop_oldest());
+
+        let typed = wordle.curr.clone();
+        let result = wordle.guess();
+
+        // guesses after the game was won aren't part of it
+        if self.explored.is_none() {
+            self.observers.guessed(wordle, &typed, &result);
+        }
+
+        if let Some(why) = rejection_message(&result) {
+            hud.toast = Some((why, Instant::now() + TOAST_TIME));
+        }
+
+        if result.is_accepted() {
+            if let (Some(race), None) = (&mut self.race, &self.explored) {
+                let clues = wordle.clues(wordle.guesses().len() - 1).unwrap();
+
+                if race.send_guess(&clues).is_err() {
+                    hud.message = Some(String::from("Lost touch with your friend"));
+                }
+            }
+
+            if !args.no_animation && args.mode == Mode::Classic {
+                self.reveal = Some((Instant::now(), wordle.guesses().len()));
+                hud.fresh = None;
+            }
+
+            hud.remaining = wordle.remaining_per_row();
+            self.row_clock = Stopwatch::start();
+
+            if hud.assist.is_some() {
+                hud.assist = Some(suggestions(wordle));
+            }
+
+            // the one thing absurdle gives away is how cornered it is
+            if let Some(left) = wordle.candidates_left().filter(|_| self.explored.is_none()) {
+                hud.message = Some(match left {
+                    1 => String::from("1 answer left"),
+                    left => format!("{left} answers left"),
+                });
+            }
+        } else {
+            if let Some(oldest) = oldest {
+                wordle.restore_oldest(oldest);
+            }
+
+            ring_bell(args, 1)?;
+
+            if !args.no_animation {
+                reject_animation(wordle, args, self.theme)?;
+                self.screen.invalidate();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records a game that ended `outcome` after `duration`, going straight on
+    /// to the next word of a marathon that isn't over.
+    fn game_over(&mut self, outcome: Outcome, duration: Duration) -> Result<Scene, WordleError> {
+        let ended = self.explored.as_ref().unwrap_or(&self.wordle);
+        self.recorder.ending(&self.args, ended, duration);
+        self.observers.on_game_end(ended);
+
+        // a marathon goes straight on to the next answer until one is lost,
+        // the clock running on across the whole run
+        if let (Some(run), Outcome::Won) = (&mut self.marathon, outcome) {
+            let points = run.solve(self.wordle.guesses().len());
+            self.wordle = new_game(&self.args);
+            run.start(&mut self.wordle);
+
+            self.hud = game_hud(&self.args, &self.wordle, self.config);
+            self.hud.marathon = self.marathon;
+            self.hud.message = Some(format!(
+                "+{points} points, next word with {} guesses",
+                self.wordle.max_guesses
+            ));
+            self.row_clock = Stopwatch::start();
+            self.screen.invalidate();
+            return Ok(Scene::Playing);
+        }
+
+        if let Some(won) = self.explored.take() {
+            self.wordle = won;
+        }
+
+        look_back(&self.wordle, outcome, &self.args, self.theme, self.config)?;
+        let unlocked = self.recorder.take_unlocked();
+
+        self.args
+            .hot_seat
+            .score(self.args.friend && outcome == Outcome::Won);
+        self.finished = true;
+
+        let mut notes = end_notes(
+            &self.wordle,
+            outcome,
+            &unlocked,
+            &self.args,
+            self.hud.opponent.as_ref(),
+            duration,
+        );
+
+        if let Some(run) = &self.marathon {
+            notes.insert(0, record_marathon(run));
+        }
+        notes.extend(leaderboard_notes(
+            &self.wordle,
+            outcome,
+            duration,
+            self.config,
+        ));
+
+        Ok(Scene::End(Ending {
+            outcome,
+            duration,
+            notes,
+            selected: 0,
+        }))
+    }
+
+    /// The menu after a game, going on to whatever is picked from it.
+    fn end_menu(&mut self, mut ending: Ending) -> Result<Scene, WordleError> {
+        let choice = end_screen(
+            &mut self.screen,
+            &self.wordle,
+            &mut ending,
+            &self.args,
+            self.theme,
+            self.config,
+        )?;
+
+        match choice {
+            MenuChoice::Quit => return Ok(Scene::Done(ending.outcome, ending.duration)),
+            MenuChoice::Stats => return Ok(Scene::Stats(ending)),
+            MenuChoice::Practice => self.args.daily = false,
+            MenuChoice::PlayAgain => {}
+        }
+
+        if self.args.friend && !secret_word(&mut self.args, self.theme, self.config)? {
+            return Ok(Scene::Done(ending.outcome, ending.duration));
+        }
+
+        self.marathon = self.args.marathon.then(Marathon::new);
+        self.next_game();
+        self.finished = false;
+        self.screen.invalidate();
+        Ok(Scene::Playing)
+    }
+
+    /// The stats, with the bar of a game just won standing out, until a key
+    /// goes back to the end menu.
+    fn stats(&mut self, ending: Ending) -> Result<Scene, WordleError> {
+        let last = (ending.outcome == Outcome::Won).then_some(self.wordle.guesses().len());
+        show_stats(&mut self.screen, &Stats::load()?, last, self.theme)?;
+        Ok(Scene::End(ending))
+    }
+
+    /// Hands the terminal back once the last game ended `outcome` after
+    /// `duration`, saving it if it was put aside, and prints what there is to
+    /// say about it.
+    fn leave(
+        mut self,
+        outcome: Outcome,
+        duration: Duration,
+        mut terminal_guard: TerminalGuard,
+    ) -> Result<ExitCode, WordleError> {
+        let args = &mut self.args;
+
+        if args.mouse {
+            execute!(std::io::stdout(), DisableMouseCapture)?;
+        }
+
+        // quitting a game that isn't over puts it aside rather than giving it up
+        if args.resume && outcome == Outcome::Forfeit {
+            drop(terminal_guard);
+
+            match SavedGame::from_game(&self.wordle).save() {
+                Ok(()) => {
+                    println!("Game saved, pick it up again with --resume");
+                    return Ok(quit_code(self.interrupted));
+                }
+                Err(err) => eprintln!("Failed to save the game: {err}"),
+            }
+
+            terminal_guard = TerminalGuard::new()?;
+        }
+
+        if !self.finished {
+            self.recorder.ending(args, &self.wordle, duration);
+            self.observers.on_game_end(&self.wordle);
+            look_back(&self.wordle, outcome, args, self.theme, self.config)?;
+        }
+
+        drop(terminal_guard);
+
+        let wordle = self.explored.unwrap_or(self.wordle);
+
+        // a finished game said all of this on the end screen already
+        if !self.finished {
+            args.hot_seat.score(args.friend && outcome == Outcome::Won);
+            let unlocked = self.recorder.take_unlocked();
+
+            let (cols, _) = terminal::size().unwrap_or((u16::MAX, 0));
+            print!(
+                "{}",
+                end_message(outcome == Outcome::Won, &wordle.answer, cols, args.ascii)
+            );
+
+            let mut notes = end_notes(
+                &wordle,
+                outcome,
+                &unlocked,
+                args,
+                self.hud.opponent.as_ref(),
+                duration,
+            );
+
+            if let Some(run) = &self.marathon {
+                notes.insert(0, record_marathon(run));
+            }
+
+            for note in notes {
+                println!("{note}");
+            }
+
+            if !args.deterministic {
+                if let Ok(stats) = Stats::load() {
+                    println!("{}", stats.summary());
+                }
+            }
+        }
+
+        if let Some(seed) = wordle.seed {
+            println!("Replay this game with {}", replay_flags(args, seed));
+        }
+
+        println!("{}", wordle.summary_line());
+
+        if let Some(path) = &args.share_to {
+            share_to(&wordle, args, path);
+        }
+
+        if args.share {
+            print!("\n{}", wordle.share_grid(args.share_ascii));
+        }
+
+        if args.copy {
+            copy_to_clipboard(&wordle.share_grid(args.share_ascii))?;
+            println!("Result copied to the clipboard");
+        }
+
+        if args.analysis && args.mode == Mode::Classic {
+            print_analysis(&wordle);
+        }
+
+        if args.show_candidates {
+            let mut history = wordle.feedback_history();
+            let mut when = "after your guesses";
+
+            if outcome != Outcome::Forfeit {
+                history.pop();
+                when = "before the final guess";
+            }
+
+            let candidates = possible_answers(&history);
+            println!("{} candidates {when}:", candidates.len());
+            println!("{}", candidates.join(" "));
+        }
+
+        Ok(quit_code(self.interrupted))
+    }
+}
+
+/// How the program exits once a game is over, the way shells expect of a
+/// program stopped by Ctrl-C when `interrupted`.
+fn quit_code(interrupted: bool) -> ExitCode {
+    if interrupted {
+        ExitCode::from(INTERRUPTED)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Loads the word lists, counting the words on stderr as they are checked so
+/// that a large list doesn't look like a hang.
+#[cfg(feature = "runtime-wordlists")]
+fn load_wordlists() -> Result<(), WordleError> {
+    use std::io::IsTerminal;
+
+    let mut stderr = std::io::stderr();
+    let show = stderr.is_terminal();
+
+    let loaded = wordle::wordlists::check(|words| {
+        if show {
+            let _ = execute!(stderr, Print(format!("\rLoading {words} words...")));
+        }
+    });
+
+    if show {
+        execute!(
+            stderr,
+            crossterm::cursor::MoveToColumn(0),
+            terminal::Clear(terminal::ClearType::CurrentLine)
+        )?;
+    }
+
+    loaded
+}
+
+fn parse_letters(letters: &str) -> Result<BTreeSet<char>, String> {
+    match letters.chars().find(|c| !c.is_alphabetic()) {
+        Some(c) => Err(format!("`{c}` isn't a letter")),
+        None => Ok(letters.to_lowercase().chars().collect()),
+    }
+}
+
+fn read_schedule(path: &Path) -> Result<Schedule, String> {
+    let text = std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    Schedule::parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Puts the lists given by `--answers` and `--guesses`, or else the ones
+/// `update-words` downloaded, in place of the usual ones, checking that every
+/// word in them is lowercase letters of one length and that every answer can
+/// be guessed. The downloaded lists only stand in for the five-letter English ones.
+fn use_custom_lists(args: &Args) -> Result<(), WordleError> {
+    for (name, path) in [("answers", &args.answers), ("guesses", &args.guesses)] {
+        // builds reading their lists from disk find the downloaded ones there already
+        #[cfg(not(feature = "runtime-wordlists"))]
+        let path = path.clone().or_else(|| {
+            update::downloaded(name).filter(|_| args.length == 5 && args.lang == Language::English)
+        });
+        #[cfg(feature = "runtime-wordlists")]
+        let path = path.clone();
+
+        let Some(path) = path else {
+            continue;
+        };
+
+        let list = match std::fs::read_to_string(&path) {
+            Ok(list) => list,
+            Err(source) => return Err(WordleError::File { path, source }),
+        };
+
+        if let Some((line, word)) = (1..)
+            .zip(list.lines())
+            .find(|(_, word)| !wordle::is_list_word(word))
+        {
+            return Err(WordleError::BadEntry {
+                list: path.display().to_string(),
+                line,
+                entry: word.to_string(),
+            });
+        }
+
+        wordle::use_custom_list(name, Box::leak(list.into_boxed_str()));
+    }
+
+    // whichever lists are used, built in or not, have to make a game possible
+    wordle::check_lists()
+}
+
+/// Downloads newer word lists with `update-words`, from the config file's
+/// `word_lists_url` or else the project's own.
+fn update_words(config: &Config) -> ExitCode {
+    let url = config
+        .word_lists_url
+        .as_deref()
+        .unwrap_or(update::DEFAULT_URL);
+    println!("Downloading word lists from {url}");
+
+    match update::update(url) {
+        Ok(lists) => {
+            for (name, words) in lists {
+                let path = update::path(name).unwrap_or_default();
+                println!("Saved {words} {name} to {}", path.display());
+            }
+
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Couldn't update the word lists: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses the flags in `WORDLE_OPTS` as if they came first on the command line,
+/// so that a flag given in both takes its value from the command line.
+fn parse_args() -> Args {
+    let opts = std::env::var("WORDLE_OPTS").unwrap_or_default();
+    Args::parse_from(with_opts(&opts, std::env::args_os()))
+}
+
+/// The command line `cli` with the flags in `opts` put in after the program name.
+fn with_opts(opts: &str, mut cli: impl Iterator<Item = OsString>) -> Vec<OsString> {
+    let program = cli.next().unwrap_or_default();
+
+    std::iter::once(program)
+        .chain(opts.split_whitespace().map(OsString::from))
+        .chain(cli)
+        .collect()
+}
+
+/// Fills in what the config file sets and the command line left out. Switches
+/// can only be turned on either way, so one set in the config stays on.
+fn apply_config(args: &mut Args, config: &Config) {
+    args.hard |= config.hard || args.ultra_hard;
+    args.no_animation |= config.no_animation;
+    args.ascii |= args.border.is_none() && !utf8_locale();
+
+    if args.ascii {
+        args.border = Some(BorderStyle::Ascii);
+        args.share_ascii = true;
+    }
+
+    args.border = args.border.or(config.border);
+    // a language's own keyboard has the keys its words need, which the
+    // layout picked for English in the config might not
+    args.layout = args.layout.or(args.lang.layout()).or(config.layout);
+
+    if args.answers.is_none() {
+        args.answers.clone_from(&config.answers);
+    }
+
+    if args.guesses.is_none() {
+        args.guesses.clone_from(&config.guesses);
+    }
+}
+
+/// Whether the locale asks for UTF-8, going by the variables that set it in
+/// order of precedence. A locale that isn't set at all is taken to be fine.
+fn utf8_locale() -> bool {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .is_none_or(|locale| {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+/// The configured theme, limited to the colors the terminal can show.
+fn load_theme(args: &Args, config: &Config) -> Theme {
+    let colors = args.colors.unwrap_or_else(ColorSupport::detect);
+    config.theme.theme(args.theme).limited(colors)
+}
+
+fn new_game(args: &Args) -> Wordle {
+    new_game_seeded(args, args.seed)
+}
+
+/// Takes out of `args` everything that would make two runs differ.
+fn make_deterministic(args: &mut Args) {
+    // everything else random is derived from the seed
+    args.seed.get_or_insert(DEMO_SEED);
+    args.row_timer = None;
+}
+
+/// Starts a game as `new_game` does, but with `seed` in place of `--seed`.
+fn new_game_seeded(args: &Args, seed: Option<u64>) -> Wordle {
+    let mut wordle = match (&args.word, seed) {
+        _ if args.tutorial => Wordle::with_answer(TUTORIAL_ANSWER),
+        _ if args.absurdle => Wordle::absurdle(),
+        // with nothing left to review, a word is picked as usual, from --seed if
+        // there is one so that --deterministic runs stay the same
+        _ if args.review => match review_word() {
+            Some(word) => Wordle::with_answer(&word),
+            None => {
+                let seed = seed.unwrap_or_else(rand::random);
+                Wordle::with_seed_from(seed, &answer_pool(args), args.allow_rude)
+            }
+        },
+        _ if args.daily => {
+            let today = chrono::Local::now().date_naive();
+
+            match args
+                .schedule
+                .as_ref()
+                .and_then(|schedule| schedule.get(today))
+            {
+                Some(answer) => Wordle::scheduled(today, answer),
+                None => Wordle::daily(today, args.daily_salt.as_deref(), args.allow_rude),
+            }
+        }
+        (Some(word), _) => Wordle::with_answer(word),
+        (None, seed) => {
+            let seed = seed.unwrap_or_else(rand::random);
+            Wordle::with_seed_from(seed, &answer_pool(args), args.allow_rude)
+        }
+    };
+
+    apply_settings(args, &mut wordle);
+    wordle
+}
+
+/// The flags that pick the same answer again from `seed`, including the ones
+/// that change which answers it is picked from, so that a friend given them
+/// plays the same game.
+fn replay_flags(args: &Args, seed: u64) -> String {
+    let mut flags = format!("--seed {seed}");
+
+    if let Some(tag) = &args.theme_words {
+        flags.push_str(&format!(" --theme-words {tag}"));
+    }
+
+    if let Some(ban) = &args.ban {
+        flags.push_str(&format!(" --ban {}", ban.iter().collect::<String>()));
+    }
+
+    if let Some(path) = &args.answers {
+        flags.push_str(&format!(" --answers {}", path.display()));
+    }
+
+    if args.max_guesses != 6 {
+        flags.push_str(&format!(" --max-guesses {}", args.max_guesses));
+    }
+
+    if args.allow_rude {
+        flags.push_str(" --allow-rude");
+    }
+
+    flags
+}
+
+/// Sets up a game with the rules the flags ask for, whatever its answer.
+fn apply_settings(args: &Args, wordle: &mut Wordle) {
+    wordle.allowed = word_list(args);
+    wordle.hint_budget = args.hint_budget;
+    wordle.banned = args.ban.clone().unwrap_or_default();
+    wordle.hard = args.hard;
+    wordle.ultra_hard = args.ultra_hard;
+    wordle.max_guesses = usize::from(args.max_guesses);
+
+    if args.easy_start {
+        wordle.lock_first_letter();
+    }
+}
+
+/// The game saved when `--resume` was last used to quit, if there is one.
+fn resume_game(args: &Args) -> Result<Option<Wordle>, String> {
+    let saved = match SavedGame::take() {
+        Ok(Some(saved)) => saved,
+        Ok(None) => return Ok(None),
+        Err(err) => return Err(format!("the save is unreadable ({err})")),
+    };
+
+    let mut wordle = Wordle::with_answer(&saved.answer);
+    apply_settings(args, &mut wordle);
+    saved.restore(&mut wordle)?;
+
+    Ok(Some(wordle))
+}
+
+/// Saves a game that is under way, or removes the save once it is over or has
+/// no guesses worth keeping.
+fn autosave(wordle: &Wordle) -> std::io::Result<()> {
+    if wordle.won().is_none() && !wordle.guesses().is_empty() {
+        SavedGame::from_game(wordle).save()
+    } else {
+        SavedGame::discard()
+    }
+}
+
+/// Time left until midnight, when the next daily puzzle comes out.
+fn until_next_daily() -> Duration {
+    let now = chrono::Local::now().naive_local();
+    let midnight = (now.date() + chrono::Days::new(1)).and_time(chrono::NaiveTime::MIN);
+
+    (midnight - now).to_std().unwrap_or_default()
+}
+
+/// Whether the answer is picked at random, or by seed, rather than given.
+fn picks_answer(args: &Args) -> bool {
+    args.word.is_none() && !args.daily && !args.absurdle && !args.review
+}
+
+/// The oldest missed word still waiting to be solved that the lists in play
+/// can take, leaving those of other lengths and languages for later.
+fn review_word() -> Option<String> {
+    Stats::load()
+        .ok()?
+        .missed
+        .into_iter()
+        .find(|word| GUESSES.contains(word))
+}
+
+/// The answers a game can be picked from, leaving out those it could never be
+/// won against. Rude ones are moved on from once picked instead, so that they
+/// don't change which answer a seed picks.
+fn answer_pool(args: &Args) -> Vec<&'static str> {
+    let answers = match &args.theme_words {
+        Some(tag) => words_in_category(tag),
+        None => ANSWERS.clone(),
+    };
+
+    let banned = args.ban.clone().unwrap_or_default();
+
+    answers
+        .into_iter()
+        .filter(|answer| !answer.chars().any(|c| banned.contains(&c)))
+        .filter(|answer| args.difficulty.allows(answer))
+        .collect()
+}
+
+/// Whether the answer, though not picked at random, can't be guessed for a banned letter.
+fn answer_is_banned(args: &Args, wordle: &Wordle) -> bool {
+    (args.word.is_some() || args.daily || args.review) && wordle.answer_is_banned()
+}
+
+fn word_list(args: &Args) -> WordList {
+    if args.answers_only {
+        WordList::Answers
+    } else if args.common_only {
+        WordList::Common
+    } else {
+        WordList::Full
+    }
+}
+
+/// Plays the guesses read from `input` in order, printing the feedback for each,
+/// for as many games as `--games` asks. Exits successfully only if every game is won.
+fn play_batch(args: &Args, input: impl BufRead) -> std::io::Result<ExitCode> {
+    let mut lines = input.lines().peekable();
+    let mut played = HashSet::new();
+    let mut wins = Vec::new();
+    let mut games = 0;
+    let mut observers = game_observers(args, None);
+
+    while games < args.games {
+        if games > 0 && lines.peek().is_none() {
+            break;
+        }
+
+        let mut wordle = batch_game(args, games, &played);
+        played.insert(wordle.answer.clone());
+        games += 1;
+
+        if answer_is_banned(args, &wordle) {
+            eprintln!("The answer has a banned letter, so this game can't be won");
+        }
+
+        if play_batch_game(args, &mut wordle, &mut lines, &mut observers)? {
+            wins.push(wordle.guesses().len());
+        }
+    }
+
+    if args.games > 1 && !args.json && !args.headless {
+        let average = wins.iter().sum::<usize>() as f64 / wins.len().max(1) as f64;
+        println!(
+            "won {} of {games} games, averaging {average:.2} guesses",
+            wins.len()
+        );
+    }
+
+    Ok(if wins.len() == games as usize {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// The game numbered `n` of a batch, avoiding the answers already `played` when
+/// the answer is picked at random. Batches with a seed go on to the seeds after it.
+fn batch_game(args: &Args, n: u64, played: &HashSet<String>) -> Wordle {
+    let seed = |retry: u64| args.seed.map(|seed| seed.wrapping_add(n + retry));
+    let mut wordle = new_game_seeded(args, seed(0));
+
+    if picks_answer(args) {
+        for retry in 1..=BATCH_RETRIES {
+            if !played.contains(&wordle.answer) {
+                break;
+            }
+
+            wordle = new_game_seeded(args, seed(retry));
+        }
+    }
+
+    wordle
+}
+
+/// Plays one game of a batch with the guesses from `lines`, returning whether it was won.
+fn play_batch_game(
+    args: &Args,
+    wordle: &mut Wordle,
+    lines: &mut impl Iterator<Item = std::io::Result<String>>,
+    observers: &mut Observers,
+) -> std::io::Result<bool> {
+    let mut log = BatchLog {
+        jotto: args.mode == Mode::Jotto,
+        ..BatchLog::default()
+    };
+
+    while wordle.won().is_none() {
+        let Some(line) = lines.next() else {
+            break;
+        };
+
+        let line = line?;
+        let word = line.trim();
+
+        if word.is_empty() {
+            continue;
+        }
+
+        wordle.curr = word.to_lowercase();
+        let result = wordle.guess();
+        log.guessed(wordle, word, &result);
+        observers.guessed(wordle, word, &result);
+
+        if let Some(reason) = result.rejection() {
+            if args.headless {
+                println!("REJECTED {reason}");
+            } else if !args.json {
+                println!("{word} REJECTED {reason}");
+            }
+
+            continue;
+        }
+
+        let Some(GuessLog { word, feedback }) = log.guesses.last() else {
+            continue;
+        };
+
+        if args.headless {
+            println!("{feedback}");
+        } else if !args.json {
+            println!("{word} {feedback}");
+        }
+    }
+
+    log.on_game_end(wordle);
+    observers.on_game_end(wordle);
+
+    if let Some(path) = &args.share_to {
+        share_to(wordle, args, path);
+    }
+
+    if args.share && !args.json {
+        print!("{}", wordle.share_grid(args.share_ascii));
+    }
+
+    let won = wordle.won() == Some(true);
+
+    if args.json {
+        println!("{}", serde_json::to_string(&log)?);
+    } else if won && !args.headless {
+        println!("won in {}", wordle.guesses().len());
+    } else if !args.headless {
+        println!("lost, the answer was {}", wordle.answer);
+    }
+
+    Ok(won)
+}
+
+/// Plays a game with `--accessible`, asking for each guess on a line of its own
+/// and answering with its clues in words, such as `C(green) R(grey) …`.
+fn play_accessible(args: &Args) -> std::io::Result<ExitCode> {
+    let mut wordle = new_game(args);
+    let mut stdout = std::io::stdout();
+    let mut lines = std::io::stdin().lock().lines();
+    let clock = Stopwatch::start();
+    let recorder = StatsRecorder::default();
+    let mut observers = game_observers(args, Some(&recorder));
+
+    println!(
+        "Find the {}-letter word in {} guesses. Type a guess and press Enter, \
+or enter nothing to give up.",
+        wordle.length(),
+        wordle.max_guesses
+    );
+
+    let outcome = loop {
+        match wordle.won() {
+            Some(true) => break Outcome::Won,
+            Some(false) => break Outcome::Lost,
+            None => {}
+        }
+
+        print!(
+            "Guess {} of {}: ",
+            wordle.guesses().len() + 1,
+            wordle.max_guesses
+        );
+        stdout.flush()?;
+
+        let Some(line) = lines.next() else {
+            println!();
+            break Outcome::Forfeit;
+        };
+
+        let word = line?.trim().to_lowercase();
+
+        if word.is_empty() {
+            break Outcome::Forfeit;
+        }
+
+        wordle.curr.clone_from(&word);
+        let result = wordle.guess();
+        observers.guessed(&wordle, &word, &result);
+
+        if let Some(why) = rejection_message(&result) {
+            println!("{why}, try again.");
+            wordle.curr.clear();
+            continue;
+        }
+
+        let row = wordle.guesses().len() - 1;
+        let guess = &wordle.guesses()[row];
+
+        let feedback = if args.mode == Mode::Jotto {
+            let common = letters_in_common(&wordle.answer, guess);
+            format!("{common} letters in common")
+        } else {
+            let clues: Vec<String> = guess
+                .chars()
+                .zip(wordle.clues(row).unwrap())
+                .map(|(c, clue)| format!("{}({})", upper(c), clue.name()))
+                .collect();
+            clues.join(" ")
+        };
+
+        println!("Guess {}: {feedback}", wordle.guesses().len());
+    };
+
+    recorder.ending(args, &wordle, clock.elapsed());
+    observers.on_game_end(&wordle);
+    let unlocked = recorder.take_unlocked();
+
+    let won = outcome == Outcome::Won;
+
+    if won {
+        let guesses = wordle.guesses().len();
+        println!("{}! Solved in {guesses}.", praise(guesses));
+    } else {
+        println!("The answer was {}.", wordle.answer.to_uppercase());
+    }
+
+    if let Some(meaning) = define(&wordle.answer) {
+        println!("{}: {meaning}", wordle.answer.to_uppercase());
+    }
+
+    for achievement in unlocked {
+        println!("{}", unlocked_message(achievement));
+    }
+
+    if let Some(stats) = Stats::load().ok().filter(|_| !args.deterministic) {
+        println!("{}", stats.summary());
+    }
+
+    Ok(if won {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Prints every guess the solver makes to find `answer`, with its clues.
+fn solve(answer: &str) -> ExitCode {
+    let answer = answer.to_lowercase();
+
+    if !ANSWERS.contains(&answer.as_str()) {
+        eprintln!("`{answer}` isn't one of the answers");
+        return ExitCode::FAILURE;
+    }
+
+    let guesses = solver::solve_by_information(&answer, 6);
+
+    for guess in &guesses {
+        println!("{guess} {}", clues_to_string(&score_guess(&answer, guess)));
+    }
+
+    println!("solved in {}", guesses.len());
+    ExitCode::SUCCESS
+}
+
+/// Plays `strategy` against every answer, or `games` of them picked by `seed`,
+/// spread over all cores with progress on stderr, then prints how many guesses
+/// it took and the answers it missed. Fails if it made a guess that isn't
+/// allowed, which would be a bug in the engine or the strategy.
+fn simulate(strategy: Strategy, games: Option<usize>, seed: u64) -> ExitCode {
+    let answers: Vec<&'static str> = match games {
+        Some(games) if games < ANSWERS.len() => ANSWERS
+            .choose_multiple(&mut StdRng::seed_from_u64(seed), games)
+            .copied()
+            .collect(),
+        _ => ANSWERS.clone(),
+    };
+
+    let total = answers.len();
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk = total.div_ceil(threads).max(1);
+    let played = std::sync::atomic::AtomicUsize::new(0);
+
+    let results: Vec<(&str, Vec<&str>)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..)
+            .step_by(chunk)
+            .zip(answers.chunks(chunk))
+            .map(|(first, chunk)| {
+                let played = &played;
+                scope.spawn(move || {
+                    (first..)
+                        .zip(chunk)
+                        .map(|(game, &answer)| {
+                            let guesses = strategy.play(answer, game);
+                            let done =
+                                played.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+
+                            if done.is_multiple_of(100) || done == total {
+                                eprint!("\rPlayed {done} of {total} games");
+                            }
+
+                            (answer, guesses)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    });
+
+    eprintln!();
+
+    let mut distribution = [0; 6];
+    let mut missed = Vec::new();
+    let mut broken = Vec::new();
+
+    for (answer, guesses) in &results {
+        if guesses.iter().any(|guess| !GUESSES.contains(guess)) {
+            broken.push(*answer);
+        }
+
+        match guesses.iter().position(|guess| guess == answer) {
+            Some(idx) => distribution[idx] += 1,
+            None => missed.push(*answer),
+        }
+    }
+
+    let solved = total - missed.len();
+    let average = (1..)
+        .zip(distribution)
+        .map(|(guesses, count)| guesses * count)
+        .sum::<usize>() as f64
+        / solved.max(1) as f64;
+
+    println!("Playing {} against {total} answers", value_name(&strategy));
+    println!("Solved {solved} within six guesses, {average:.3} guesses on average");
+    println!();
+    println!("Guess distribution:");
+
+    for (guesses, count) in (1..).zip(distribution) {
+        println!("{guesses}: {count}");
+    }
+
+    if !missed.is_empty() {
+        missed.sort_unstable();
+        println!();
+        println!("Missed: {}", missed.join(" "));
+    }
+
+    if !broken.is_empty() {
+        eprintln!(
+            "Guessed words that aren't allowed against: {}",
+            broken.join(" ")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Replays a claimed result, accepting it only if every guess is valid and the
+/// last of them wins the game.
+fn verify(args: &Args, guesses: &[String]) -> ExitCode {
+    let mut wordle = new_game(args);
+
+    for guess in guesses.iter().map(|guess| guess.trim()) {
+        if wordle.won().is_some() {
+            println!("INVALID: `{guess}` was guessed after the game was over");
+            return ExitCode::FAILURE;
+        }
+
+        wordle.curr = guess.to_lowercase();
+
+        if let Some(reason) = wordle.guess().rejection() {
+            println!("INVALID: `{guess}` is rejected ({reason})");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let seed = wordle.seed.unwrap_or_default();
+    let count = wordle.guesses().len();
+
+    if wordle.won() == Some(true) {
+        println!(
+            "VALID: won with seed {seed} in {count}/{}",
+            wordle.max_guesses
+        );
+        ExitCode::SUCCESS
+    } else {
+        println!("INVALID: not a win with seed {seed} after {count} guesses");
+        ExitCode::FAILURE
+    }
+}
+
+/// Prints the two logged games side by side, failing if either can't be read
+/// or they weren't played against the same answer.
+fn compare_logs(paths: &[PathBuf]) -> ExitCode {
+    let logs: Result<Vec<(String, BatchLog)>, String> = paths
+        .iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap_or(path.as_os_str());
+            let json = std::fs::read_to_string(path)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            let log =
+                serde_json::from_str(&json).map_err(|err| format!("{}: {err}", path.display()))?;
+
+            Ok((name.to_string_lossy().into_owned(), log))
+        })
+        .collect();
+
+    match logs.and_then(|logs| compare(&logs[0], &logs[1])) {
+        Ok(comparison) => {
+            print!("{comparison}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// A table of two named games' guesses with their clues, the guesses they had
+/// in common and which of them did better.
+fn compare(
+    (name_a, log_a): &(String, BatchLog),
+    (name_b, log_b): &(String, BatchLog),
+) -> Result<String, String> {
+    if log_a.answer != log_b.answer {
+        return Err(format!(
+            "Can't compare games of different puzzles, {name_a} had {} and {name_b} had {}",
+            log_a.answer.to_uppercase(),
+            log_b.answer.to_uppercase()
+        ));
+    }
+
+    // the clues are worked out again rather than trusted from the logs
+    let game = |log: &BatchLog| {
+        let mut wordle = Wordle::with_answer(&log.answer);
+        wordle.set_guesses(log.guesses.iter().map(|guess| guess.word.clone()).collect());
+        wordle
+    };
+
+    let (game_a, game_b) = (game(log_a), game(log_b));
+    let rows = |wordle: &Wordle| -> Vec<String> {
+        wordle
+            .feedback_history()
+            .into_iter()
+            .map(|(guess, clues)| format!("{} {}", guess.to_uppercase(), clues_to_string(&clues)))
+            .collect()
+    };
+
+    let (rows_a, rows_b) = (rows(&game_a), rows(&game_b));
+    let width = name_a.len().max(11);
+
+    let mut table = format!("   {name_a:width$}  {name_b}\n");
+
+    for idx in 0..rows_a.len().max(rows_b.len()) {
+        let a = rows_a.get(idx).map_or("", String::as_str);
+        let b = rows_b.get(idx).map_or("", String::as_str);
+        table.push_str(format!("{:>2} {a:width$}  {b}", idx + 1).trim_end());
+        table.push('\n');
+    }
+
+    let mut shared: Vec<String> = game_a
+        .guesses()
+        .iter()
+        .filter(|guess| game_b.guesses().contains(guess))
+        .map(|guess| guess.to_uppercase())
+        .collect();
+    shared.dedup();
+
+    table.push('\n');
+
+    if shared.is_empty() {
+        table.push_str("No guesses in common\n");
+    } else {
+        table.push_str(&format!("In common: {}\n", shared.join(", ")));
+    }
+
+    let score = |wordle: &Wordle| (wordle.won() == Some(true)).then_some(wordle.guesses().len());
+
+    let verdict = match (score(&game_a), score(&game_b)) {
+        (Some(a), Some(b)) if a == b => format!("A tie, both solved it in {a}"),
+        (Some(a), Some(b)) if a < b => format!("{name_a} wins, {a} guesses to {b}"),
+        (Some(a), Some(b)) => format!("{name_b} wins, {b} guesses to {a}"),
+        (Some(a), None) => format!("{name_a} wins, solving it in {a} while {name_b} didn't"),
+        (None, Some(b)) => format!("{name_b} wins, solving it in {b} while {name_a} didn't"),
+        (None, None) => String::from("Neither of you solved it"),
+    };
+
+    table.push_str(&verdict);
+    table.push('\n');
+
+    Ok(table)
+}
+
+/// Prints the rows of a shared grid one at a time as colored tiles. With a known
+/// answer, each row is filled in with a word that would have got those colors.
+fn replay_grid(args: &Args, theme: &Theme, grid: &str) {
+    let rows = parse_emoji_grid(grid);
+    let answer =
+        (args.word.is_some() || args.seed.is_some() || args.daily).then(|| new_game(args).answer);
+
+    if rows.is_empty() {
+        println!("No rows of colored squares found");
+        return;
+    }
+
+    for clues in rows {
+        let word = answer.as_deref().and_then(|answer| {
+            // answers first, as shared games more likely used everyday words
+            ANSWERS
+                .iter()
+                .chain(GUESS_LIST.iter())
+                .find(|guess| score_guess(answer, guess) == clues)
+        });
+
+        let letters: Vec<char> = match word {
+            Some(word) => word.chars().map(upper).collect(),
+            None => vec![' '; clues.len()],
+        };
+
+        let tiles: String = clues
+            .iter()
+            .zip(letters)
+            .map(|(&clue, c)| {
+                let tile = format!(" {c} ").with(theme.clue(clue)).reverse().bold();
+                format!("{tile} ")
+            })
+            .collect();
+
+        println!("{}", tiles.trim_end());
+
+        if !args.no_animation {
+            std::thread::sleep(Duration::from_millis(300));
+        }
+    }
+}
+
+/// A game played without the TUI, as printed by `--json` and read back by `--compare`.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct BatchLog {
+    answer: String,
+    won: bool,
+    seed: Option<u64>,
+    guesses: Vec<GuessLog>,
+    rejected: Vec<RejectedLog>,
+    /// Whether the game is jotto, which logs letters in common rather than clues.
+    #[serde(skip)]
+    jotto: bool,
+}
+
+/// The log is kept as the game is played, the same way as any other observer.
+impl GameObserver for BatchLog {
+    fn on_guess(&mut self, game: &Wordle, guess: &str, clues: &[Clue]) {
+        let feedback = if self.jotto {
+            letters_in_common(&game.answer, guess).to_string()
+        } else {
+            clues_to_string(clues)
+        };
+
+        self.guesses.push(GuessLog {
+            word: guess.to_string(),
+            feedback,
+        });
+    }
+
+    fn on_invalid(&mut self, _: &Wordle, guess: &str, why: &GuessResult) {
+        self.rejected.push(RejectedLog {
+            word: guess.to_string(),
+            reason: why.rejection().unwrap_or_default().to_string(),
+        });
+    }
+
+    fn on_game_end(&mut self, game: &Wordle) {
+        self.answer.clone_from(&game.answer);
+        self.won = game.won() == Some(true);
+        self.seed = game.seed;
+    }
+}
+
+/// The observers a game tells what happens in it: `recorder`, for the games
+/// that are kept in the stats, and any asked for by the flags. Deterministic
+/// games are never kept, as the date and time taken would differ every run.
+fn game_observers(args: &Args, recorder: Option<&StatsRecorder>) -> Observers {
+    let mut observers = Observers::default();
+
+    if let Some(recorder) = recorder.filter(|_| !args.deterministic) {
+        observers.register(recorder.clone());
+    }
+
+    if let Some(command) = &args.event_hook {
+        observers.register(hook::EventHook::new(command.clone()));
+    }
+
+    observers
+}
+
+#[derive(Serialize, Deserialize)]
+struct GuessLog {
+    word: String,
+    /// Clues such as `GYBBB`, or the number of letters in common for jotto.
+    feedback: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RejectedLog {
+    word: String,
+    reason: String,
+}
+
+/// Solves every answer in the list and reports how hard each one was, listing
+/// the words that took more than six guesses and the hardest ones overall.
+fn audit_list(answers: &[&'static str]) {
+    let opener = solver::suggest(answers);
+    let limit = 20;
+
+    let mut results: Vec<(&str, usize)> = answers
+        .iter()
+        .map(|&answer| {
+            let guesses = solver::solve(answer, answers, opener, limit);
+            let solved = guesses.last() == Some(&answer);
+            (answer, if solved { guesses.len() } else { limit + 1 })
+        })
+        .collect();
+
+    results.sort_by_key(|&(answer, guesses)| (std::cmp::Reverse(guesses), answer));
+
+    let total: usize = results.iter().map(|&(_, guesses)| guesses).sum();
+    let unsolved: Vec<_> = results
+        .iter()
+        .filter(|&&(_, guesses)| guesses > 6)
+        .collect();
+
+    println!("Opening with {}", opener.to_uppercase());
+    println!(
+        "Solved {} of {} answers within six guesses, {:.3} guesses on average",
+        answers.len() - unsolved.len(),
+        answers.len(),
+        total as f64 / answers.len() as f64,
+    );
+
+    if !unsolved.is_empty() {
+        println!();
+        println!("Not solved within six guesses:");
+
+        for (answer, guesses) in &unsolved {
+            match *guesses {
+                guesses if guesses > limit => println!("{answer} unsolved"),
+                guesses => println!("{answer} {guesses}"),
+            }
+        }
+    }
+
+    println!();
+    println!("Hardest words:");
+
+    for (answer, guesses) in results.iter().take(20) {
+        println!("{answer} {guesses}");
+    }
+}
+
+/// Ranks every valid guess as an opener, showing progress on stderr since it
+/// takes a while, and prints the top 20 with the answers each leaves on average.
+fn best_openers() {
+    let total = GUESS_LIST.len();
+
+    let ranked = solver::rank_openers(&GUESS_LIST, &ANSWERS, |scored| {
+        if scored % 100 == 0 || scored == total {
+            eprint!("\rScored {scored} of {total} openers");
+        }
+    });
+
+    eprintln!();
+
+    for (guess, score) in ranked.iter().take(20) {
+        println!("{guess} {score:.3}");
+    }
+}
+
+/// Lines printed once the game is over, falling back to shorter ones for lines
+/// that wouldn't fit in `width` columns.
+fn end_message(won: bool, answer: &str, width: u16, ascii: bool) -> String {
+    let lines = if won && ascii {
+        vec![(
+            String::from("*** You have won!!! ***"),
+            String::from("You won!"),
+        )]
+    } else if won {
+        vec![(
+            String::from("🦀🦀🦀 You have won!!! 🦀🦀🦀"),
+            String::from("🦀 You won! 🦀"),
+        )]
+    } else {
+        let answer = answer.to_uppercase();
+
+        vec![
+            (
+                format!("The answer was {answer}."),
+                format!("Answer: {answer}"),
+            ),
+            (
+                String::from("Maybe try again later..."),
+                String::from("Try again later"),
+            ),
+        ]
+    };
+
+    let mut message = String::new();
+
+    for (long, short) in lines {
+        let line = if display_width(&long) <= width as usize {
+            long
+        } else {
+            short
+        };
+
+        message.push_str(&line);
+        message.push('\n');
+    }
+
+    message
+}
+
+/// The HUD a game after the first starts out with.
+fn game_hud(args: &Args, wordle: &Wordle, config: &Config) -> Hud {
+    Hud {
+        status: (!config.hide_status_bar).then(|| GameContext {
+            mode: mode_name(args, wordle),
+            streak: Stats::load().map_or(0, |stats| stats.current_streak()),
+            elapsed: Duration::ZERO,
+        }),
+        message: args.show_dupes.then(|| dupes_message(wordle)),
+        opponent: args.versus_bot.then(|| Opponent::bot(wordle)),
+        ..Hud::default()
+    }
+}
+
+/// The screens going over a game that was lost or given up on, as asked for.
+fn look_back(
+    wordle: &Wordle,
+    outcome: Outcome,
+    args: &Args,
+    theme: &Theme,
+    config: &Config,
+) -> std::io::Result<()> {
+    if outcome == Outcome::Lost && args.coach {
+        coach_screen(wordle, theme)?;
+    }
+
+    if outcome != Outcome::Won && args.coach_walkthrough {
+        walkthrough(wordle, theme, config)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MenuChoice {
+    PlayAgain,
+    /// A random word after the daily puzzle, which can't be played again.
+    Practice,
+    Stats,
+    Quit,
+}
+
+/// What there is to say about a game once it is over, beyond whether it was
+/// won, a line each.
+fn end_notes(
+    wordle: &Wordle,
+    outcome: Outcome,
+    unlocked: &[Achievement],
+    args: &Args,
+    opponent: Option<&Opponent>,
+    duration: Duration,
+) -> Vec<String> {
+    let won = outcome == Outcome::Won;
+    let mut notes = Vec::new();
+
+    if let Some(meaning) = define(&wordle.answer) {
+        notes.push(format!("{}: {meaning}", wordle.answer.to_uppercase()));
+    }
+
+    notes.extend(unlocked.iter().copied().map(unlocked_message));
+
+    if args.friend {
+        notes.push(format!("Solved so far: {}", args.hot_seat.tally()));
+    }
+
+    if let Some(opponent) = opponent {
+        notes.push(opponent.verdict(won, wordle.guesses().len(), duration));
+    }
+
+    if args.speedrun && won {
+        notes.push(speedrun_verdict(duration));
+    }
+
+    let rating = solver::difficulty_rating(&wordle.answer) as usize;
+    let (full, empty) = if args.ascii {
+        ("*", "-")
+    } else {
+        ("★", "☆")
+    };
+    notes.push(format!(
+        "Difficulty: {}{}",
+        full.repeat(rating),
+        empty.repeat(5 - rating)
+    ));
+
+    if wordle.hints > 0 {
+        notes.push(format!("Hints used: {}", wordle.hints));
+    }
+
+    if args.tutorial {
+        notes.push(String::from(
+            "That's the tutorial done, the next game counts",
+        ));
+    }
+
+    if wordle.undos > 0 {
+        notes.push(format!(
+            "Guesses taken back: {}, so this game is unranked",
+            wordle.undos
+        ));
+    }
+
+    if wordle.day.is_some() {
+        notes.push(format!(
+            "The next puzzle is in {}",
+            stats::fmt_duration(until_next_daily())
+        ));
+    }
+
+    notes
+}
+
+/// Shows how the game went once it is over: the answer, the result grid, the
+/// stats and the notes of the `ending`, then asks what to do next, leaving the
+/// menu on the choice picked.
+fn end_screen(
+    screen: &mut Screen,
+    wordle: &Wordle,
+    ending: &mut Ending,
+    args: &Args,
+    theme: &Theme,
+    config: &Config,
+) -> std::io::Result<MenuChoice> {
+    let again = match wordle.day {
+        Some(_) => (MenuChoice::Practice, String::from("Practice a random word")),
+        None => (MenuChoice::PlayAgain, String::from("Play again")),
+    };
+    let choices = [
+        again,
+        (MenuChoice::Stats, String::from("Stats")),
+        (MenuChoice::Quit, String::from("Quit")),
+    ];
+
+    let won = ending.outcome == Outcome::Won;
+    let notes = &ending.notes;
+    let headline = if won {
+        format!(
+            "{}! Solved in {}/{}",
+            praise(wordle.guesses().len()),
+            wordle.guesses().len(),
+            wordle.max_guesses
+        )
+    } else {
+        String::from("The answer was")
+    };
+
+    // the rows of the result grid, without the line naming the game
+    let grid = wordle.share_grid(args.ascii || args.share_ascii);
+    let grid: Vec<&str> = grid.lines().skip(2).collect();
+
+    let summary = Stats::load()
+        .ok()
+        .filter(|_| !args.deterministic)
+        .map(|stats| stats.summary());
+
+    let selected = &mut ending.selected;
+    screen.invalidate();
+
+    loop {
+        let (cols, rows) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows);
+        let center = |len: usize| (cols / 2).saturating_sub(len as u16 / 2);
+
+        let height = 6 + grid.len() + usize::from(summary.is_some()) + notes.len() + choices.len();
+        let mut y = rows.saturating_sub(height as u16) / 2;
+
+        let color = if won { theme.green } else { theme.warning };
+        frame.print(
+            center(display_width(&headline)),
+            y,
+            headline.as_str().with(color).bold(),
+        );
+        y += 2;
+
+        // the answer turned over as a row of green tiles
+        let x = center(4 * wordle.length() - 1);
+        for (idx, c) in wordle.answer.chars().enumerate() {
+            let tile = theme.tile(c, Clue::Green).with(theme.green).bold();
+            frame.print(x + 4 * idx as u16, y, tile);
+        }
+        y += 2;
+
+        for row in &grid {
+            frame.print(center(display_width(row)), y, row.stylize());
+            y += 1;
+        }
+        y += 1;
+
+        for line in summary.iter().chain(notes) {
+            frame.print(
+                center(display_width(line)),
+                y,
+                line.as_str().with(theme.grey),
+            );
+            y += 1;
+        }
+        y += 1;
+
+        for (idx, (y, (_, label))) in (y..).zip(&choices).enumerate() {
+            let line = format!(" {label} ");
+
+            if idx == *selected {
+                frame.print(center(display_width(&line)), y, line.reverse().bold());
+            } else {
+                frame.print(center(display_width(&line)), y, line.stylize());
+            }
+        }
+
+        screen.draw(frame)?;
+
+        let Event::Key(KeyEvent { code, .. }) = event::read()? else {
+            continue;
+        };
+
+        match config.keys.action(code) {
+            Some(Action::Quit) => return Ok(MenuChoice::Quit),
+            Some(Action::Submit) => return Ok(choices[*selected].0),
+            Some(Action::Up) => *selected = selected.saturating_sub(1),
+            Some(Action::Down) => *selected = (*selected + 1).min(choices.len() - 1),
+            _ => {}
+        }
+    }
+}
+
+/// Lets the player pick one of `choices` with the arrow keys, under `title` and
+/// above the lines of `note`. `selected` starts out on a choice and is left on the one
+/// picked, for when the menu comes back. Gives `None` if they quit instead.
+fn pick<T: Copy>(
+    screen: &mut Screen,
+    title: &str,
+    choices: &[(T, String)],
+    selected: &mut usize,
+    note: Option<&str>,
+    theme: &Theme,
+    config: &Config,
+) -> std::io::Result<Option<T>> {
+    loop {
+        let (cols, rows) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows);
+        let center = |len: usize| (cols / 2).saturating_sub(len as u16 / 2);
+
+        let y = rows.saturating_sub(2 + choices.len() as u16) / 2;
+        frame.print(center(display_width(title)), y, title.bold());
+
+        for (idx, (y, (_, label))) in (y + 2..).zip(choices).enumerate() {
+            let line = format!(" {label} ");
+
+            if idx == *selected {
+                frame.print(center(display_width(&line)), y, line.reverse().bold());
+            } else {
+                frame.print(center(display_width(&line)), y, line.stylize());
+            }
+        }
+
+        if let Some(note) = note {
+            for (y, line) in (y + choices.len() as u16 + 3..).zip(note.lines()) {
+                frame.print(center(display_width(line)), y, line.with(theme.grey));
+            }
+        }
+
+        screen.draw(frame)?;
+
+        let Event::Key(KeyEvent { code, .. }) = event::read()? else {
+            continue;
+        };
+
+        match config.keys.action(code) {
+            Some(Action::Quit) => return Ok(None),
+            Some(Action::Submit) => return Ok(Some(choices[*selected].0)),
+            Some(Action::Up) => *selected = selected.saturating_sub(1),
+            Some(Action::Down) => *selected = (*selected + 1).min(choices.len() - 1),
+            _ => {}
+        }
+    }
+}
+
+/// Shows the stats in the middle of the screen until a key is pressed, with the
+/// guess distribution drawn as bars. `last` is the number of guesses a game
+/// just won took, whose bar stands out. Left and right switch between every
+/// game and each kind of game on its own.
+fn show_stats(
+    screen: &mut Screen,
+    stats: &Stats,
+    last: Option<usize>,
+    theme: &Theme,
+) -> std::io::Result<()> {
+    if stats.games.is_empty() {
+        let back = "Press any key to go back";
+        show_lines(
+            screen,
+            &["No games played yet"],
+            0,
+            |_, _, _| {},
+            back,
+            theme,
+        )?;
+        return Ok(());
+    }
+
+    // every game first, then each kind on its own when there is more than one
+    let kinds = stats.kinds();
+    let views: Vec<Option<&GameKind>> = match kinds.len() {
+        1 => vec![None],
+        _ => [None].into_iter().chain(kinds.iter().map(Some)).collect(),
+    };
+    let footer = match views.len() {
+        1 => "Press any key to go back",
+        _ => "Left and right for each kind of game, any other key to go back",
+    };
+    let mut view = 0;
+
+    loop {
+        let (title, only) = match views[view] {
+            None => (String::from("All games"), None),
+            Some(kind) => (kind.label(), Some(stats.only(kind))),
+        };
+        let shown = only.as_ref().unwrap_or(stats);
+        let distribution = shown.distribution();
+        // a line either side of the title and a bar for each number of guesses
+        let chart_rows = distribution.len() + 3;
+
+        // what belongs to every kind at once is only shown with all of them
+        let overview = shown.overview();
+        let details = match only {
+            None => stats.details(),
+            Some(_) => String::new(),
+        };
+        let lines: Vec<&str> = [title.as_str(), ""]
+            .into_iter()
+            .chain(overview.lines())
+            .chain(std::iter::repeat_n("", chart_rows))
+            .chain(details.lines())
+            .collect();
+        let chart_at = overview.lines().count() as u16 + 3;
+
+        let key = show_lines(
+            screen,
+            &lines,
+            chart_at,
+            |frame, (x, y), width| {
+                frame.print(x, y, "Guess distribution:".stylize());
+                render_distribution(frame, (x, y + 1), width, &distribution, last, theme);
+            },
+            footer,
+            theme,
+        )?;
+
+        view = match key {
+            KeyCode::Right => (view + 1) % views.len(),
+            KeyCode::Left => (view + views.len() - 1) % views.len(),
+            _ => return Ok(()),
+        };
+    }
+}
+
+/// Shows `lines` in the middle of the screen until a key is pressed, letting
+/// `draw` fill in more from `line` down, given where that is and how wide the
+/// lines are. Gives the key pressed.
+fn show_lines(
+    screen: &mut Screen,
+    lines: &[&str],
+    line: u16,
+    draw: impl Fn(&mut Frame, (u16, u16), u16),
+    footer: &str,
+    theme: &Theme,
+) -> std::io::Result<KeyCode> {
+    let width = lines
+        .iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or(0);
+
+    loop {
+        let (cols, rows) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows);
+        let center = |len: usize| (cols / 2).saturating_sub(len as u16 / 2);
+        let y = rows.saturating_sub(lines.len() as u16 + 2) / 2;
+
+        for (y, line) in (y..).zip(lines) {
+            frame.print(center(width), y, line.stylize());
+        }
+
+        draw(&mut frame, (center(width), y + line), width as u16);
+
+        let y = y + lines.len() as u16 + 1;
+        frame.print(center(footer.len()), y, footer.with(theme.grey));
+
+        screen.draw(frame)?;
+
+        if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+            return Ok(code);
+        }
+    }
+}
+
+/// Draws the wins for each number of guesses as bars as long as `width` at
+/// most, with the count at their end, the one for `last` guesses in green.
+fn render_distribution(
+    frame: &mut Frame,
+    (x, y): (u16, u16),
+    width: u16,
+    distribution: &[usize],
+    last: Option<usize>,
+    theme: &Theme,
+) {
+    let most = distribution.iter().copied().max().unwrap_or(0).max(1);
+    // the numbers of guesses are lined up when some have two digits
+    let digits = distribution.len().to_string().len();
+    let room = (width as usize).saturating_sub(digits + 1).max(8);
+
+    for (guesses, (y, &count)) in (1..).zip((y..).zip(distribution)) {
+        let label = count.to_string();
+        let len = (count * room / most).max(label.len() + 1);
+        let bar = format!("{label:>len$}");
+        let color = if last == Some(guesses) {
+            theme.green
+        } else {
+            theme.grey
+        };
+
+        frame.print(x, y, format!("{guesses:>digits$} ").stylize());
+        frame.print(x + digits as u16 + 1, y, bar.with(color).reverse());
+    }
+}
+
+#[derive(Clone, Copy)]
+enum StartChoice {
+    Classic,
+    Daily,
+    Hard,
+    Boards,
+    Timed,
+    Marathon,
+    Stats,
+    Settings,
+    Quit,
+}
+
+/// The menu shown when the game is started without any flags, which sets up
+/// `args` for the kind of game picked. Returns false if the player quit.
+fn main_menu(args: &mut Args, config: &Config) -> std::io::Result<bool> {
+    let choices = [
+        (StartChoice::Classic, String::from("Classic")),
+        (StartChoice::Daily, String::from("Daily puzzle")),
+        (StartChoice::Hard, String::from("Hard mode")),
+        (StartChoice::Boards, format!("{MENU_BOARDS} boards at once")),
+        (StartChoice::Timed, String::from("Against the clock")),
+        (StartChoice::Marathon, String::from("Marathon")),
+        (StartChoice::Stats, String::from("Stats")),
+        (StartChoice::Settings, String::from("Settings")),
+        (StartChoice::Quit, String::from("Quit")),
+    ];
+
+    let mut screen = Screen::default();
+    let mut selected = 0;
+    let mut note: Option<String> = None;
+
+    loop {
+        let theme = load_theme(args, config);
+        let shown = note.take();
+        let choice = pick(
+            &mut screen,
+            "Wordle",
+            &choices,
+            &mut selected,
+            shown.as_deref(),
+            &theme,
+            config,
+        )?;
+
+        match choice {
+            None | Some(StartChoice::Quit) => return Ok(false),
+            Some(StartChoice::Classic) => {}
+            Some(StartChoice::Daily) => {
+                args.daily = true;
+                let day = new_game(args).day;
+
+                if day.is_some_and(|day| Stats::load().is_ok_and(|stats| stats.played_daily(day))) {
+                    args.daily = false;
+                    note = Some(format!(
+                        "Today's puzzle is done, the next one is in {}",
+                        stats::fmt_duration(until_next_daily())
+                    ));
+                    continue;
+                }
+            }
+            Some(StartChoice::Hard) => args.hard = true,
+            Some(StartChoice::Boards) => args.boards = Some(MENU_BOARDS),
+            Some(StartChoice::Timed) => args.speedrun = true,
+            Some(StartChoice::Marathon) => args.marathon = true,
+            Some(StartChoice::Stats) => {
+                show_stats(&mut screen, &Stats::load()?, None, &theme)?;
+                continue;
+            }
+            Some(StartChoice::Settings) => {
+                settings_menu(&mut screen, args, config)?;
+                continue;
+            }
+        }
+
+        return Ok(true);
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Setting {
+    Theme,
+    Border,
+    Layout,
+    Back,
+}
+
+/// Lets the player flip through the themes, borders and keyboard layouts, for
+/// as long as the game runs.
+fn settings_menu(screen: &mut Screen, args: &mut Args, config: &Config) -> std::io::Result<()> {
+    let mut selected = 0;
+
+    loop {
+        let theme = load_theme(args, config);
+        let preset = args.theme.or(config.theme.preset).unwrap_or_default();
+        let border = args.border.unwrap_or_default();
+        let layout = args.layout.unwrap_or_default();
+
+        let choices = [
+            (Setting::Theme, format!("Theme: {}", value_name(&preset))),
+            (Setting::Border, format!("Border: {}", value_name(&border))),
+            (
+                Setting::Layout,
+                format!("Keyboard: {}", value_name(&layout)),
+            ),
+            (Setting::Back, String::from("Back")),
+        ];
+        let note = "Put these in the config file to keep them";
+
+        match pick(
+            screen,
+            "Settings",
+            &choices,
+            &mut selected,
+            Some(note),
+            &theme,
+            config,
+        )? {
+            Some(Setting::Theme) => args.theme = Some(next_value(&preset)),
+            Some(Setting::Border) => args.border = Some(next_value(&border)),
+            Some(Setting::Layout) => args.layout = Some(next_value(&layout)),
+            Some(Setting::Back) | None => return Ok(()),
+        }
+    }
+}
+
+/// The name `value` is given on the command line.
+fn value_name<T: ValueEnum>(value: &T) -> String {
+    value
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+/// The value after `value` in the order the command line lists them, going
+/// back to the first after the last.
+fn next_value<T: ValueEnum + Clone>(value: &T) -> T {
+    let values = T::value_variants();
+    let idx = values
+        .iter()
+        .position(|other| value_name(other) == value_name(value))
+        .unwrap_or(0);
+
+    values[(idx + 1) % values.len()].clone()
+}
+
+/// How many different letters the answer has, without giving any of them away.
+fn dupes_message(wordle: &Wordle) -> String {
+    match wordle.distinct_letters() {
+        distinct if distinct == wordle.length() => String::from("No letter repeats in this word"),
+        distinct => format!("This word has {distinct} distinct letters"),
+    }
+}
+
+/// Why a guess was turned down, in words for the player.
+fn rejection_message(result: &GuessResult) -> Option<String> {
+    Some(match result {
+        GuessResult::Accepted => return None,
+        GuessResult::TooShort => String::from("Not enough letters"),
+        GuessResult::TooLong => String::from("Too many letters"),
+        GuessResult::NotAWord => String::from("Not in word list"),
+        GuessResult::Banned(c) => format!("{} is banned", upper(*c)),
+        GuessResult::MissingGreen(idx, c) => {
+            format!("{} letter must be {}", ordinal(idx + 1), upper_letter(c))
+        }
+        GuessResult::MissingLetter(c) => format!("Guess must contain {}", upper_letter(c)),
+        GuessResult::GreyLetter(c, 0) => format!("{} isn't in the word", upper_letter(c)),
+        GuessResult::GreyLetter(c, found) => {
+            format!("The word only has {found} {}", upper_letter(c))
+        }
+        GuessResult::YellowSpot(idx, c) => {
+            format!("{} letter can't be {}", ordinal(idx + 1), upper_letter(c))
+        }
+        GuessResult::Repeated => String::from("Already guessed"),
+    })
+}
+
+/// `1st`, `2nd` and so on, for the positions in a word.
+fn ordinal(n: usize) -> String {
+    let suffix = match n {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    };
+
+    format!("{n}{suffix}")
+}
+
+/// Praise shown for a win, keyed by the number of guesses it took.
+fn praise(guesses: usize) -> &'static str {
+    match guesses {
+        1 => "Genius",
+        2 => "Magnificent",
+        3 => "Impressive",
+        4 => "Splendid",
+        5 => "Great",
+        _ => "Phew",
+    }
+}
+
+/// A short definition of `word`, from the bundled ones or, in builds that can,
+/// the dictionary online.
+fn define(word: &str) -> Option<String> {
+    #[cfg(feature = "online-definitions")]
+    {
+        definition(word)
+            .map(String::from)
+            .or_else(|| dictionary::lookup(word))
+    }
+
+    #[cfg(not(feature = "online-definitions"))]
+    {
+        definition(word).map(String::from)
+    }
+}
+
+/// Sends a daily game to the leaderboard server set in the config, if there
+/// is one, and gives the day's standings a line each.
+#[cfg(feature = "online-leaderboard")]
+fn leaderboard_notes(
+    wordle: &Wordle,
+    outcome: Outcome,
+    duration: Duration,
+    config: &Config,
+) -> Vec<String> {
+    let (Some(server), Some(day)) = (&config.leaderboard, wordle.day) else {
+        return Vec::new();
+    };
+
+    let me = server.player();
+    let sent = server.submit(
+        day,
+        &leaderboard::Entry {
+            player: me.clone(),
+            guesses: wordle.guesses().len(),
+            won: outcome == Outcome::Won,
+            seconds: duration.as_secs(),
+        },
+    );
+
+    let Some(standings) = server.standings(day) else {
+        return vec![String::from("Couldn't reach the leaderboard")];
+    };
+
+    let mut notes = vec![if sent {
+        String::from("Today's leaderboard:")
+    } else {
+        String::from("Today's leaderboard, without your result:")
+    }];
+
+    for (rank, entry) in (1..).zip(standings.iter().take(LEADERBOARD_ROWS)) {
+        let score = match entry.won {
+            true => entry.guesses.to_string(),
+            false => String::from("X"),
+        };
+        let you = if entry.player == me { " (you)" } else { "" };
+
+        notes.push(format!(
+            "{rank}. {} {score}/{} {}{you}",
+            entry.player.get(..8).unwrap_or(&entry.player),
+            wordle.max_guesses,
+            fmt_clock(Duration::from_secs(entry.seconds))
+        ));
+    }
+
+    notes
+}
+
+#[cfg(not(feature = "online-leaderboard"))]
+fn leaderboard_notes(_: &Wordle, _: Outcome, _: Duration, _: &Config) -> Vec<String> {
+    Vec::new()
+}
+
+/// Adds every game it is told has ended to the stats file, and so to the
+/// history, as one of the game's observers. What the game itself doesn't say,
+/// the front end playing it passes on through `ending` just before.
+#[derive(Clone, Default)]
+struct StatsRecorder(Rc<RefCell<Recording>>);
+
+/// What a `StatsRecorder` records a game with besides the game itself.
+#[derive(Default)]
+struct Recording {
+    /// The kind of game, as `game_mode` names it.
+    mode: &'static str,
+    /// Whether the game is the tutorial, which is left out of the rankings.
+    tutorial: bool,
+    /// Time spent on the game, leaving out pauses.
+    duration: Duration,
+    /// The achievements the last game recorded unlocked.
+    unlocked: Vec<Achievement>,
+}
+
+impl StatsRecorder {
+    /// Gets ready to record `wordle`, which is about to end `duration` into it.
+    fn ending(&self, args: &Args, wordle: &Wordle, duration: Duration) {
+        let mut recording = self.0.borrow_mut();
+        recording.mode = game_mode(args, wordle);
+        recording.tutorial = args.tutorial;
+        recording.duration = duration;
+    }
+
+    /// The achievements unlocked by the game recorded last, if they haven't
+    /// been taken yet.
+    fn take_unlocked(&self) -> Vec<Achievement> {
+        std::mem::take(&mut self.0.borrow_mut().unlocked)
+    }
+}
+
+impl GameObserver for StatsRecorder {
+    fn on_game_end(&mut self, game: &Wordle) {
+        let mut recording = self.0.borrow_mut();
+        recording.unlocked = record_game(&recording, game);
+    }
+}
+
+/// Adds the game to the stats file, giving the achievements it unlocked. The
+/// tutorial and games with guesses taken back are left out of the rankings.
+fn record_game(recording: &Recording, wordle: &Wordle) -> Vec<Achievement> {
+    let outcome = match wordle.won() {
+        Some(true) => Outcome::Won,
+        Some(false) => Outcome::Lost,
+        None => Outcome::Forfeit,
+    };
+
+    let result = Stats::load().and_then(|mut stats| {
+        let unlocked = stats.record_game(GameRecord {
+            date: chrono::Local::now().date_naive(),
+            answer: wordle.answer.clone(),
+            guesses: wordle.guesses().len(),
+            outcome,
+            hints: wordle.hints,
+            words: wordle.guesses().to_vec(),
+            day: wordle.day,
+            seconds: Some(recording.duration.as_secs()),
+            hard: wordle.hard,
+            mode: Some(recording.mode.to_string()),
+            unranked: recording.tutorial || wordle.undos > 0,
+        });
+
+        stats.save().map(|()| unlocked)
+    });
+
+    result.unwrap_or_else(|err| {
+        eprintln!("Failed to save stats: {err}");
+        Vec::new()
+    })
+}
+
+/// The line announcing a newly unlocked achievement.
+fn unlocked_message(achievement: Achievement) -> String {
+    format!(
+        "Achievement unlocked: {} ({})",
+        achievement.name(),
+        achievement.description()
+    )
+}
+
+/// Keeps the score of a marathon run that is over if it is the best yet,
+/// saying how the run went.
+fn record_marathon(run: &Marathon) -> String {
+    let result = Stats::load().and_then(|mut stats| {
+        let before = stats.marathon_best;
+        let best = stats.record_marathon(run.score);
+        stats.save().map(|()| (best, before))
+    });
+
+    let words = match run.solved {
+        1 => String::from("1 word"),
+        solved => format!("{solved} words"),
+    };
+    let line = format!("Marathon over: {words} for {} points", run.score);
+
+    match result {
+        Ok((true, _)) => format!("{line}, a new high score!"),
+        Ok((false, before)) => format!("{line}, your best is {before}"),
+        Err(err) => {
+            eprintln!("Failed to save stats: {err}");
+            line
+        }
+    }
+}
+
+/// How a win `duration` long compares to the fastest win before it, the game
+/// itself having been recorded last.
+fn speedrun_verdict(duration: Duration) -> String {
+    let time = fmt_clock(duration);
+    let stats = Stats::load().unwrap_or_default();
+    let before = &stats.games[..stats.games.len().saturating_sub(1)];
+
+    match stats::fastest_win(before) {
+        Some(best) if best <= duration.as_secs() => {
+            let best = fmt_clock(Duration::from_secs(best));
+            format!("Solved in {time}, your best is {best}")
+        }
+        Some(_) => format!("Solved in {time}, a new personal best!"),
+        None => format!("Solved in {time}, your first timed win"),
+    }
+}
+
+/// A duration down to the second, such as `1:05`, for timing games.
+fn fmt_clock(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Prints how each guess did against the best one there was, the way it was
+/// judged into scores for skill and luck.
+fn print_analysis(wordle: &Wordle) {
+    let analysis = solver::analyze(&wordle.answer, wordle.guesses());
+
+    if analysis.is_empty() {
+        return;
+    }
+
+    println!("\nGuess  Before  After  Bits  Best   Bits");
+
+    for step in &analysis {
+        println!(
+            "{}  {:>6}  {:>5}  {:>4.1}  {}  {:>4.1}",
+            step.guess.to_uppercase(),
+            step.before,
+            step.after,
+            step.expected,
+            step.best.to_uppercase(),
+            step.best_expected,
+        );
+    }
+
+    println!(
+        "Skill {}/100 · Luck {}/100",
+        solver::skill(&analysis),
+        solver::luck(&analysis)
+    );
+}
+
+/// Prints the keyboard with every letter in the color of the clue it got most
+/// often, ties going to the more informative one.
+fn print_heatmap(stats: &Stats, theme: &Theme, layout: KeyboardLayout) {
+    let heatmap = stats::letter_heatmap(&stats.games);
+
+    if heatmap.is_empty() {
+        println!("No guesses recorded yet");
+        return;
+    }
+
+    for (indent, row) in (0..).zip(layout.rows()) {
+        let keys: Vec<String> = row
+            .chars()
+            .map(|c| {
+                let key = upper(c);
+
+                match heatmap.get(&c) {
+                    Some(counts) => {
+                        let most = counts.iter().max().unwrap();
+                        let clue =
+                            Clue::ALL[counts.iter().position(|count| count == most).unwrap()];
+                        key.with(theme.clue(clue)).bold().to_string()
+                    }
+                    None => key.dim().to_string(),
+                }
+            })
+            .collect();
+
+        println!("{:indent$}{}", "", keys.join(" "), indent = 2 * indent);
+    }
+}
+
+/// Asks the terminal to put `text` on the clipboard with an OSC 52 sequence,
+/// which works over SSH too, in the terminals that support it.
+fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+    stdout.flush()
+}
+
+/// Standard base64 with padding, as OSC 52 wants it.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::new();
+
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (idx, &byte)| n | (byte as u32) << (16 - 8 * idx));
+
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * idx) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+fn share_to(wordle: &Wordle, args: &Args, path: &Path) {
+    if let Err(err) = std::fs::write(path, wordle.share_grid(args.share_ascii)) {
+        eprintln!("Failed to write share grid to {}: {err}", path.display());
+    }
+}
+
+/// The board as it stands for `--mirror`: a line for each row, with the clues
+/// as colored tiles with `--mirror-color` or after the letters otherwise.
+fn mirror_snapshot(wordle: &Wordle, args: &Args, theme: &Theme) -> String {
+    let mut snapshot = String::new();
+
+    // a terminal showing the colored copy draws each one over the last
+    if args.mirror_color {
+        snapshot.push_str("\x1b[H\x1b[2J");
+    }
+
+    snapshot.push_str(&format!(
+        "{} {}/{}\n\n",
+        mode_name(args, wordle),
+        wordle.guesses().len(),
+        wordle.max_guesses
+    ));
+
+    for row in 0..wordle.max_guesses {
+        let line = match (wordle.guesses().get(row), wordle.clues(row)) {
+            (Some(guess), Some(clues)) if args.mirror_color => guess
+                .chars()
+                .zip(clues)
+                .map(|(c, clue)| {
+                    let tile = theme.tile(c, clue).with(theme.clue(clue)).bold();
+                    tile.reverse().to_string()
+                })
+                .collect(),
+            (Some(guess), Some(clues)) => {
+                let clues: String = clues
+                    .into_iter()
+                    .map(if args.share_ascii {
+                        Clue::ascii
+                    } else {
+                        Clue::emoji
+                    })
+                    .collect();
+                format!("{} {clues}", guess.to_uppercase())
+            }
+            (Some(guess), None) => guess.to_uppercase(),
+            (None, _) if args.mirror_color => " _ ".repeat(wordle.length()),
+            (None, _) => "_".repeat(wordle.length()),
+        };
+
+        snapshot.push_str(&line);
+        snapshot.push('\n');
+    }
+
+    match wordle.won() {
+        Some(true) => snapshot.push_str("\nSolved!\n"),
+        Some(false) => snapshot.push_str(&format!(
+            "\nThe answer was {}\n",
+            wordle.answer.to_uppercase()
+        )),
+        None => {}
+    }
+
+    snapshot
+}
+
+/// Number of terminal columns `s` takes up, counting wide glyphs such as emoji as two.
+fn display_width(s: &str) -> usize {
+    UnicodeWidthStr::width(s)
+}
+
+/// Rows the grid takes up with room for `guesses` guesses.
+fn grid_height(guesses: usize) -> u16 {
+    2 * guesses as u16 + 1
+}
+
+/// Columns the grid takes up for words of `length` letters.
+fn grid_width(length: usize) -> u16 {
+    4 * length as u16 + 1
+}
+
+/// Columns and rows needed to show the grid and everything around it.
+fn layout_size(args: &Args) -> (u16, u16) {
+    let width = grid_width(wordle::word_length());
+    // marathon games have as many rows as guesses left, so room is kept for the most
+    let rows = if args.marathon {
+        Marathon::MAX
+    } else {
+        args.max_guesses.into()
+    };
+    let height = grid_height(rows);
+
+    if let Some(count) = args.boards {
+        let count = u16::from(count);
+        ((width + 2) * count - 2, 2 * (count + 5) + 4)
+    } else if shows_keyboard(args) {
+        let layout = args.layout.unwrap_or_default();
+        (
+            width.max(4 * layout.width() as u16),
+            height + 3 + 2 * layout.rows().len() as u16 + u16::from(args.mouse),
+        )
+    } else {
+        (width, height + 2)
+    }
+}
+
+/// Top left corner of the grid, centered in the terminal along with everything below it.
+fn grid_origin(args: &Args) -> std::io::Result<(u16, u16)> {
+    let (cols, rows) = terminal::size()?;
+    let (_, height) = layout_size(args);
+    let width = grid_width(wordle::word_length());
+    Ok((
+        cols.saturating_sub(width) / 2,
+        rows.saturating_sub(height) / 2,
+    ))
+}
+
+/// Hands the terminal back and stops the program, as Ctrl-Z does outside raw
+/// mode, then takes the terminal over again once it is brought back.
+fn suspend(mouse: bool) -> std::io::Result<()> {
+    guard::restore();
+
+    #[cfg(unix)]
+    std::process::Command::new("kill")
+        .args(["-TSTP", &std::process::id().to_string()])
+        .status()?;
+
+    guard::reenter()?;
+
+    if mouse {
+        execute!(std::io::stdout(), EnableMouseCapture)?;
+    }
+
+    Ok(())
+}
+
+/// Makes sure there is a terminal to take over and draw the game in, as
+/// output sent elsewhere, or a terminal that doesn't say how big it is, would
+/// only get garbled.
+fn check_terminal() -> Result<(), WordleError> {
+    use std::io::IsTerminal;
+
+    if !std::io::stdout().is_terminal() {
+        return Err(WordleError::NotATerminal);
+    }
+
+    match terminal::size() {
+        Ok((cols, rows)) if cols > 0 && rows > 0 => Ok(()),
+        _ => Err(WordleError::UnknownTerminalSize),
+    }
+}
+
+/// Asks for a bigger terminal until the layout fits, returning false if the
+/// player quit instead.
+fn wait_for_size(args: &Args, config: &Config) -> std::io::Result<bool> {
+    let (width, height) = layout_size(args);
+    let mut stdout = std::io::stdout();
+
+    loop {
+        let (cols, rows) = terminal::size()?;
+
+        if cols >= width && rows >= height {
+            execute!(stdout, terminal::Clear(terminal::ClearType::All))?;
+            return Ok(true);
+        }
+
+        let lines = [
+            format!("Please resize to {width}x{height}"),
+            format!("(current: {cols}x{rows})"),
+        ];
+
+        queue!(stdout, terminal::Clear(terminal::ClearType::All))?;
+
+        for (y, line) in (rows.saturating_sub(2) / 2..).zip(lines) {
+            let x = cols.saturating_sub(display_width(&line) as u16) / 2;
+            queue!(stdout, MoveTo(x, y), Print(line))?;
+        }
+
+        stdout.flush()?;
+
+        if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+            if config.keys.action(code) == Some(Action::Quit) {
+                return Ok(false);
+            }
+        }
+    }
+}
+
+/// A typed letter as it is drawn, hidden behind a dot when it is `masked`.
+fn shown_letter(c: char, masked: bool) -> char {
+    if masked {
+        '•'
+    } else {
+        upper(c)
+    }
+}
+
+/// The players of `--friend`, who take turns picking the answer.
+const PLAYERS: [&str; 2] = ["one", "two"];
+
+/// How a game of `--friend` stands: the number of answers picked so far, and
+/// of games each player solved.
+#[derive(Clone, Default)]
+struct HotSeat {
+    games: usize,
+    solved: [usize; 2],
+}
+
+impl HotSeat {
+    /// The player picking the next answer, who guessed the last one.
+    fn setter(&self) -> usize {
+        self.games % 2
+    }
+
+    /// Counts a win for whoever guessed the last answer, if `won`.
+    fn score(&mut self, won: bool) {
+        if won && self.games > 0 {
+            self.solved[self.setter()] += 1;
+        }
+    }
+
+    fn tally(&self) -> String {
+        format!(
+            "Player one {} · Player two {}",
+            self.solved[0], self.solved[1]
+        )
+    }
+}
+
+/// Lets the player whose turn it is type in the answer without showing it,
+/// setting it as `--word` and passing the turn on. Gives false if they quit
+/// instead.
+fn secret_word(args: &mut Args, theme: &Theme, config: &Config) -> std::io::Result<bool> {
+    let setter = args.hot_seat.setter();
+    let mut screen = Screen::default();
+    let mut word = String::new();
+    let mut error: Option<String> = None;
+
+    loop {
+        let (cols, rows) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows);
+        let center = |len: usize| (cols / 2).saturating_sub(len as u16 / 2);
+        let y = rows.saturating_sub(7) / 2;
+
+        let title = format!("Player {}, type the answer", PLAYERS[setter]);
+        frame.print(center(title.len()), y, title.bold());
+
+        let aside = format!("Player {}, look away!", PLAYERS[1 - setter]);
+        frame.print(center(aside.len()), y + 1, aside.with(theme.grey));
+
+        let tiles: Vec<String> = (0..wordle::word_length())
+            .map(|idx| match word.chars().nth(idx) {
+                Some(c) => shown_letter(c, true).to_string(),
+                None => String::from("_"),
+            })
+            .collect();
+        let tiles = tiles.join(" ");
+        frame.print(center(tiles.len()), y + 3, tiles.with(theme.cursor).bold());
+
+        if let Some(error) = &error {
+            frame.print(
+                center(display_width(error)),
+                y + 5,
+                error.as_str().with(theme.warning),
+            );
+        }
+
+        if args.hot_seat.games > 0 {
+            let tally = format!("Solved so far: {}", args.hot_seat.tally());
+            frame.print(center(display_width(&tally)), y + 7, tally.with(theme.grey));
+        }
+
+        screen.draw(frame)?;
+
+        let Event::Key(KeyEvent { code, .. }) = event::read()? else {
+            continue;
+        };
+
+        match config.keys.action(code) {
+            Some(Action::Quit) => return Ok(false),
+
+            Some(Action::Erase) => {
+                word.pop();
+            }
+
+            Some(Action::Submit) => match check_secret(args, &word) {
+                Ok(()) => {
+                    args.word = Some(word);
+                    args.hot_seat.games += 1;
+                    return Ok(true);
+                }
+                Err(err) => error = Some(err),
+            },
+
+            Some(_) => {}
+
+            None => {
+                if let KeyCode::Char(c) = code {
+                    if c.is_alphabetic() && letter_count(&word) < wordle::word_length() {
+                        word.extend(c.to_lowercase());
+                        error = None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Why `word` can't be the answer player one picked, if it can't: it has to be
+/// a known word of the length in play that player two is allowed to guess.
+fn check_secret(args: &Args, word: &str) -> Result<(), String> {
+    let length = wordle::word_length();
+    if letter_count(word) != length {
+        return Err(format!("The answer needs {length} letters"));
+    }
+
+    let upper = word.to_uppercase();
+
+    if !GUESSES.contains(word) {
+        return Err(format!("{upper} isn't a word"));
+    }
+
+    let mut wordle = Wordle::with_answer(word);
+    wordle.allowed = word_list(args);
+    wordle.banned = args.ban.clone().unwrap_or_default();
+
+    if !wordle.dictionary().contains(word) {
+        Err(format!("{upper} isn't allowed as a guess"))
+    } else if wordle.answer_is_banned() {
+        Err(format!("{upper} has a banned letter"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Draws a frame, trying again a few times with `--soft-fail` when the write
+/// fails in a way that can clear up on its own. Anything else, such as the
+/// terminal going away, still ends the game.
+fn draw_retrying(
+    args: &Args,
+    screen: &mut Screen,
+    mut draw: impl FnMut(&mut Screen) -> std::io::Result<()>,
+) -> std::io::Result<()> {
+    let mut retries = 0;
+
+    loop {
+        match draw(screen) {
+            Err(err) if args.soft_fail && retries < RENDER_RETRIES && is_transient(&err) => {
+                retries += 1;
+                guard::warn(format!("Failed to draw the screen, retried: {err}"));
+
+                // part of the frame may have made it out, so redraw all of it
+                screen.invalidate();
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            result => return result,
+        }
+    }
+}
+
+fn is_transient(err: &std::io::Error) -> bool {
+    use std::io::ErrorKind;
+
+    matches!(
+        err.kind(),
+        ErrorKind::Interrupted | ErrorKind::WouldBlock | ErrorKind::TimedOut
+    )
+}
+
+/// Rings the terminal bell `times` times with `--bell`: once for a rejected
+/// guess, twice for a win and three times for a loss. Nothing is rung unless
+/// stdout is a terminal, so the bytes never end up in a file.
+fn ring_bell(args: &Args, times: usize) -> std::io::Result<()> {
+    use std::io::IsTerminal;
+
+    let mut stdout = std::io::stdout();
+
+    if !args.bell || !stdout.is_terminal() {
+        return Ok(());
+    }
+
+    for ring in 0..times {
+        if ring > 0 {
+            std::thread::sleep(Duration::from_millis(150));
+        }
+
+        stdout.write_all(b"\x07")?;
+        stdout.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Shakes the current row from side to side to show that the guess was rejected.
+fn reject_animation(wordle: &Wordle, args: &Args, theme: &Theme) -> std::io::Result<()> {
+    let (x, y) = grid_origin(args)?;
+    let y = y + 2 * wordle.guesses().len() as u16 + 1;
+
+    let [_, mid, _, _] = args.border.unwrap_or_default().rows(wordle.length());
+    let mut row: Vec<char> = mid.chars().collect();
+
+    for (idx, c) in wordle.curr.chars().enumerate().take(wordle.length()) {
+        row[4 * idx + 2] = shown_letter(c, args.blind);
+    }
+
+    let row: String = row.into_iter().collect();
+
+    let mut stdout = std::io::stdout();
+
+    for shift in [1, -1, 1, -1] {
+        let x = x.saturating_add_signed(shift);
+        queue!(
+            stdout,
+            MoveTo(x.saturating_sub(1), y),
+            PrintStyledContent(format!(" {row} ").with(theme.border))
+        )?;
+        stdout.flush()?;
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    Ok(())
+}
+
+/// Lists the games played, newest first, to pick one with the arrow keys and
+/// step through its guesses again.
+fn history(stats: &Stats, args: &Args, theme: &Theme, config: &Config) -> std::io::Result<()> {
+    let games: Vec<&GameRecord> = stats.games.iter().rev().collect();
+    let mut selected: usize = 0;
+    let mut screen = Screen::default();
+
+    loop {
+        let (cols, rows) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows);
+
+        let width = 40;
+        let x = cols.saturating_sub(width) / 2;
+        let shown = usize::from(rows.saturating_sub(6)).max(1);
+        let first = selected.saturating_sub(shown - 1);
+
+        frame.print(x, 1, format!("{} games played", games.len()).bold());
+
+        for (y, (idx, game)) in (3..).zip(games.iter().enumerate().skip(first).take(shown)) {
+            let result = match game.outcome {
+                Outcome::Won => format!("won in {}", game.guesses),
+                outcome => outcome.as_str().to_string(),
+            };
+            let hard = if game.hard { " hard" } else { "" };
+            let line = format!(
+                "{}  {}  {result}{hard}",
+                game.date,
+                game.answer.to_uppercase()
+            );
+            let line = format!("{line:width$}", width = width as usize);
+
+            if idx == selected {
+                frame.print(x, y, line.reverse());
+            } else {
+                frame.print(x, y, line.stylize());
+            }
+        }
+
+        let footer = format!(
+            "{} to step through a game, {} to quit",
+            config.keys.submit.name(),
+            config.keys.quit.name()
+        );
+        frame.print(x, rows.saturating_sub(2), footer.with(theme.grey));
+
+        screen.draw(frame)?;
+
+        let Event::Key(KeyEvent { code, .. }) = event::read()? else {
+            continue;
+        };
+
+        match config.keys.action(code) {
+            Some(Action::Quit) => return Ok(()),
+            Some(Action::Up) => selected = selected.saturating_sub(1),
+            Some(Action::Down) => selected = (selected + 1).min(games.len().saturating_sub(1)),
+            Some(Action::Submit) if !games.is_empty() => {
+                replay_game(games[selected], args, theme, config)?;
+                screen.invalidate();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Shows the grid of a recorded game a guess at a time, a key per guess.
+fn replay_game(
+    game: &GameRecord,
+    args: &Args,
+    theme: &Theme,
+    config: &Config,
+) -> std::io::Result<()> {
+    let mut screen = Screen::default();
+    let length = letter_count(&game.answer);
+    let [top, mid, int, bot] = args.border.unwrap_or_default().rows(length);
+
+    for shown in 0..=game.words.len() {
+        let (cols, rows) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows);
+
+        let height = 2 * game.words.len().max(1) as u16 + 5;
+        let x = cols.saturating_sub(grid_width(length)) / 2;
+        let y = rows.saturating_sub(height) / 2;
+
+        let title = format!("{} · {}", game.date, game.answer.to_uppercase());
+        frame.print(
+            cols.saturating_sub(display_width(&title) as u16) / 2,
+            y,
+            title.bold(),
+        );
+
+        let mut grid: Vec<&str> = std::iter::repeat_n([&*mid, &*int], game.words.len().max(1))
+            .flatten()
+            .collect();
+        grid.pop();
+        grid.push(&bot);
+        grid.insert(0, &top);
+
+        for (y, row) in (y + 1..).zip(&grid) {
+            frame.print(x, y, row.with(theme.border));
+        }
+
+        for (y, word) in (y + 2..).step_by(2).zip(&game.words).take(shown) {
+            if word.is_empty() {
+                for x in (x + 2..).step_by(4).take(length) {
+                    frame.print(x, y, '×'.with(theme.warning));
+                }
+
+                continue;
+            }
+
+            let clues = score_guess(&game.answer, word);
+
+            for ((x, c), clue) in (x + 1..).step_by(4).zip(word.chars()).zip(clues) {
+                frame.print(x, y, theme.tile(c, clue).with(theme.clue(clue)).bold());
+            }
+        }
+
+        let footer = if shown < game.words.len() {
+            "Press any key for the next guess"
+        } else {
+            "Press any key to go back"
+        };
+        frame.print(
+            cols.saturating_sub(footer.len() as u16) / 2,
+            y + grid.len() as u16 + 2,
+            footer.with(theme.grey),
+        );
+
+        screen.draw(frame)?;
+
+        loop {
+            if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+                if config.keys.action(code) == Some(Action::Quit) {
+                    return Ok(());
+                }
+
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Steps through the guesses the solver would have made from the same opener,
+/// a key at a time, next to the guesses actually made and the answers each left.
+fn walkthrough(wordle: &Wordle, theme: &Theme, config: &Config) -> std::io::Result<()> {
+    let Some(opener) = wordle
+        .guesses()
+        .iter()
+        .find(|guess| !guess.is_empty())
+        .and_then(|opener| GUESS_LIST.iter().copied().find(|word| word == opener))
+    else {
+        return Ok(());
+    };
+
+    let yours: Vec<(&str, usize)> = wordle
+        .guesses()
+        .iter()
+        .map(String::as_str)
+        .zip(wordle.remaining_per_row())
+        .collect();
+
+    let mut remaining = ANSWERS.clone();
+    let solver: Vec<(&str, usize)> = solver::solve(&wordle.answer, &ANSWERS, opener, 10)
+        .into_iter()
+        .map(|guess| {
+            let clues = score_guess(&wordle.answer, guess);
+            remaining.retain(|answer| score_guess(answer, guess) == clues);
+            (guess, remaining.len())
+        })
+        .collect();
+
+    let steps = yours.len().max(solver.len());
+    let mut screen = Screen::default();
+
+    for shown in 1..=steps {
+        let (cols, rows) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows);
+
+        let width = 34;
+        let x = cols.saturating_sub(width) / 2;
+        let y = rows.saturating_sub(steps as u16 + 6) / 2;
+        let title = format!("The answer was {}.", wordle.answer.to_uppercase());
+        frame.print(x, y, title.bold());
+        frame.print(
+            x,
+            y + 1,
+            "Numbers are the answers left after each guess".with(theme.grey),
+        );
+        frame.print(x + 5, y + 3, "You".with(theme.grey));
+        frame.print(x + 19, y + 3, "Solver".with(theme.grey));
+
+        for step in 0..shown {
+            let y = y + 4 + step as u16;
+            frame.print(x, y, format!("{:>2}", step + 1).with(theme.grey));
+
+            let columns = [(x + 5, yours.get(step)), (x + 19, solver.get(step))];
+
+            for (x, guess) in columns {
+                let Some(&(guess, left)) = guess else {
+                    continue;
+                };
+
+                if guess.is_empty() {
+                    frame.print(x, y, "×××××".with(theme.warning));
+                } else {
+                    let clues = score_guess(&wordle.answer, guess);
+
+                    for ((x, c), clue) in (x..).zip(guess.chars()).zip(clues) {
+                        frame.print(x, y, upper(c).with(theme.clue(clue)).bold());
+                    }
+                }
+
+                frame.print(x + 6, y, format!("{left:>4}").stylize());
+            }
+        }
+
+        let footer = if shown < steps {
+            "Press any key for the next step"
+        } else {
+            "Press any key to finish"
+        };
+        frame.print(x, y + 5 + steps as u16, footer.with(theme.grey));
+
+        screen.draw(frame)?;
+
+        loop {
+            if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+                if config.keys.action(code) == Some(Action::Quit) {
+                    return Ok(());
+                }
+
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Rules out the answers that wouldn't have given `clues` for `guess`, and picks
+/// the solver's next guess from those left, if any are.
+fn next_reverse_guess(
+    candidates: &mut Vec<&'static str>,
+    guess: &str,
+    clues: &[Clue],
+) -> Option<&'static str> {
+    candidates.retain(|answer| clues == score_guess(answer, guess));
+    solver::information_guess(candidates)
+}
+
+/// Reverse mode: the solver guesses a word the player thinks of, and the player
+/// gives the clues for each guess with `g`, `y` and `b`. Ends once the word is
+/// found, the clues rule out every answer, or the player quits, giving whether
+/// it was found.
+fn reverse(theme: &Theme, config: &Config) -> std::io::Result<bool> {
+    let mut candidates = ANSWERS.clone();
+    let mut rows: Vec<(&str, Vec<Clue>)> = Vec::new();
+    let mut guess = solver::information_guess(&candidates);
+    let mut clues: Vec<Clue> = Vec::new();
+    let mut screen = Screen::default();
+
+    loop {
+        let found = rows
+            .last()
+            .is_some_and(|(_, clues)| clues.iter().all(|&clue| clue == Clue::Green));
+
+        let (cols, rows_left) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows_left);
+
+        let width = 52;
+        let x = cols.saturating_sub(width) / 2;
+        let y = rows_left.saturating_sub(rows.len() as u16 + 8) / 2;
+
+        frame.print(x, y, "Think of a word for the solver to guess".bold());
+        frame.print(
+            x,
+            y + 1,
+            "Give each letter its clue: g green, y yellow, b grey".with(theme.grey),
+        );
+
+        for (y, (word, clues)) in (y + 3..).zip(&rows) {
+            for ((x, c), &clue) in (x..).step_by(4).zip(word.chars()).zip(clues) {
+                frame.print(x, y, theme.tile(c, clue).with(theme.clue(clue)).reverse());
+            }
+        }
+
+        let y = y + 3 + rows.len() as u16;
+
+        let footer = match guess {
+            _ if found => format!("Found it in {}! Press any key to finish", rows.len()),
+            None => String::from("No answer fits those clues. Press any key to finish"),
+            Some(word) => {
+                for (idx, (x, c)) in (x..).step_by(4).zip(word.chars()).enumerate() {
+                    match clues.get(idx) {
+                        Some(&clue) => {
+                            frame.print(x, y, theme.tile(c, clue).with(theme.clue(clue)).reverse())
+                        }
+                        None if idx == clues.len() => {
+                            frame.print(x, y, theme.tile(c, Clue::Grey).with(theme.cursor).bold())
+                        }
+                        None => frame.print(x, y, theme.tile(c, Clue::Grey).stylize()),
+                    }
+                }
+
+                match candidates.len() {
+                    1 => String::from("1 possible answer left, Enter to send the clues"),
+                    left => format!("{left} possible answers left, Enter to send the clues"),
+                }
+            }
+        };
+
+        frame.print(x, y + 2, footer.with(theme.grey));
+        screen.draw(frame)?;
+
+        let Event::Key(KeyEvent { code, .. }) = event::read()? else {
+            continue;
+        };
+
+        let Some(word) = guess.filter(|_| !found) else {
+            return Ok(found);
+        };
+
+        let clue = match code {
+            KeyCode::Char('g') => Some(Clue::Green),
+            KeyCode::Char('y') => Some(Clue::Yellow),
+            KeyCode::Char('b') => Some(Clue::Grey),
+            _ => None,
+        };
+
+        match (clue, config.keys.action(code)) {
+            (Some(clue), _) if clues.len() < letter_count(word) => clues.push(clue),
+            (Some(_), _) => {}
+            (None, Some(Action::Quit)) => return Ok(false),
+            (None, Some(Action::Erase)) => {
+                clues.pop();
+            }
+            (None, Some(Action::Submit)) if clues.len() == letter_count(word) => {
+                guess = next_reverse_guess(&mut candidates, word, &clues);
+                rows.push((word, std::mem::take(&mut clues)));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether the letter just typed, turning `before` into the current guess,
+/// filled the row with a word the game accepts, or `None` if it didn't fill it.
+fn filled_row(wordle: &Wordle, before: &str) -> Option<bool> {
+    (wordle.curr != before && letter_count(&wordle.curr) == wordle.length())
+        .then(|| wordle.dictionary().contains(&wordle.curr))
+}
+
+/// The coach's line listing the answers one letter away from `answer`.
+fn neighbors_line(answer: &str) -> String {
+    // the more of these there are, the more guesses luck can cost
+    let neighbors = solver::one_letter_neighbors(answer, &ANSWERS);
+
+    if neighbors.is_empty() {
+        return String::from("No other answer is one letter away, a truly unique word.");
+    }
+
+    let mut shown: Vec<String> = neighbors
+        .iter()
+        .take(COACH_NEIGHBORS)
+        .map(|word| word.to_uppercase())
+        .collect();
+
+    if neighbors.len() > COACH_NEIGHBORS {
+        shown.push(String::from("…"));
+    }
+
+    format!("One letter away: {}", shown.join(", "))
+}
+
+/// Loss screen showing the closest guess next to the answer, along with the
+/// letters of the answer that were never found, until a key is pressed.
+fn coach_screen(wordle: &Wordle, theme: &Theme) -> std::io::Result<()> {
+    let history = wordle.feedback_history();
+    let found = |clue: &Clue| *clue != Clue::Grey;
+
+    let mut lines: Vec<Vec<StyledContent<String>>> = Vec::new();
+
+    // the first of the guesses that found the most letters
+    let best = history
+        .iter()
+        .rev()
+        .max_by_key(|(_, clues)| clues.iter().filter(|clue| found(clue)).count());
+
+    match best {
+        Some((guess, clues)) => {
+            let greens = clues.iter().filter(|&&clue| clue == Clue::Green).count();
+            let yellows = clues.iter().filter(|&&clue| clue == Clue::Yellow).count();
+
+            lines.push(vec![format!(
+                "Your closest guess found {greens} green and {yellows} yellow:"
+            )
+            .stylize()]);
+
+            lines.push(
+                guess
+                    .chars()
+                    .zip(clues)
+                    .map(|(c, &clue)| theme.tile(c, clue).with(theme.clue(clue)).bold())
+                    .collect(),
+            );
+        }
+
+        None => lines.push(vec![
+            String::from("You didn't get to make a guess.").stylize()
+        ]),
+    }
+
+    lines.push(Vec::new());
+    lines.push(vec![String::from(
+        "The answer, colored by how well you found it:",
+    )
+    .stylize()]);
+    lines.push(
+        wordle
+            .answer
+            .chars()
+            .zip(wordle.answer_coverage())
+            .map(|(c, clue)| theme.tile(c, clue).with(theme.clue(clue)).bold())
+            .collect(),
+    );
+    lines.push(Vec::new());
+
+    let mut unknown: Vec<String> = wordle
+        .answer
+        .chars()
+        .filter(|&c| {
+            !history.iter().any(|(guess, clues)| {
+                guess
+                    .chars()
+                    .zip(clues)
+                    .any(|(g, clue)| g == c && found(clue))
+            })
+        })
+        .map(|c| upper(c).to_string())
+        .collect();
+    unknown.sort();
+    unknown.dedup();
+
+    lines.push(vec![if unknown.is_empty() {
+        String::from("You found every letter, only their order was missing.").stylize()
+    } else {
+        format!("You never found: {}", unknown.join(", ")).stylize()
+    }]);
+
+    lines.push(vec![neighbors_line(&wordle.answer).stylize()]);
+
+    lines.push(Vec::new());
+    lines.push(vec![
+        String::from("Press any key to continue").with(theme.grey)
+    ]);
+
+    let mut screen = Screen::default();
+
+    // drawn again whenever something other than a key comes in, so that it
+    // stays centered through a resize
+    loop {
+        let (cols, rows) = terminal::size()?;
+        let mut frame = Frame::new(cols, rows);
+        let center = |len: usize| (cols / 2).saturating_sub(len as u16 / 2);
+        let y = rows.saturating_sub(lines.len() as u16) / 2;
+
+        for (y, line) in (y..).zip(&lines) {
+            let len = line.iter().map(|part| display_width(part.content())).sum();
+            let mut x = center(len);
+
+            for part in line {
+                frame.print(x, y, part.clone());
+                x += display_width(part.content()) as u16;
+            }
+        }
+
+        screen.draw(frame)?;
+
+        if let Event::Key(_) = event::read()? {
+            return Ok(());
+        }
+    }
+}
+
+/// Draws the keyboard with every letter colored by its best clue so far and, with
+/// `marks`, under each key the positions (1 to 5) a yellow letter has been ruled out of.
+fn render_keyboard(
+    frame: &mut Frame,
+    wordle: &Wordle,
+    theme: &Theme,
+    layout: KeyboardLayout,
+    y: u16,
+    marks: bool,
+) {
+    let states = wordle.letter_states();
+    let exclusions = if marks {
+        wordle.yellow_exclusions()
+    } else {
+        HashMap::new()
+    };
+    let cols = frame.width();
+
+    for (y, row) in (y..).step_by(2).zip(layout.rows()) {
+        let x = (cols / 2).saturating_sub(2 * row.chars().count() as u16);
+
+        for (x, c) in (x..).step_by(4).zip(row.chars()) {
+            let color = states
+                .get(&c)
+                .map_or(theme.cursor, |&clue| theme.clue(clue));
+            let marks: String = exclusions
+                .get(&c)
+                .into_iter()
+                .flatten()
+                .map(|idx| char::from(b'1' + *idx as u8))
+                .collect();
+
+            frame.print(x + 1, y, upper(c).with(color).bold());
+            frame.print(x, y + 1, format!("{marks:^3}").with(theme.yellow));
+        }
+    }
+}
+
+fn shows_keyboard(args: &Args) -> bool {
+    args.keyboard || args.advanced_keyboard || args.mouse
+}
+
+/// What a click on the keyboard below the grid stands for.
+#[derive(Clone, Copy)]
+enum Click {
+    Letter(char),
+    Submit,
+    Erase,
+}
+
+/// The buttons below the keyboard with `--mouse`, with the column each starts at.
+fn keyboard_buttons(cols: u16, ascii: bool) -> [(Click, u16, &'static str); 2] {
+    let enter = "  ENTER  ";
+    let erase = if ascii { "  DEL  " } else { "  ⌫  " };
+    let width = (display_width(enter) + 2 + display_width(erase)) as u16;
+    let x = (cols / 2).saturating_sub(width / 2);
+
+    [
+        (Click::Submit, x, enter),
+        (Click::Erase, x + display_width(enter) as u16 + 2, erase),
+    ]
+}
+
+/// What was clicked at `(column, row)` on the keyboard drawn from row `y`
+/// down, laid out as `render_keyboard` and the buttons below it are.
+fn keyboard_click(
+    layout: KeyboardLayout,
+    cols: u16,
+    y: u16,
+    ascii: bool,
+    (column, row): (u16, u16),
+) -> Option<Click> {
+    let rows = layout.rows();
+    let below = row.checked_sub(y)?;
+
+    // each key takes up its letter and the row of marks under it
+    if let Some(letters) = rows.get(below as usize / 2) {
+        let x = (cols / 2).saturating_sub(2 * letters.chars().count() as u16);
+        let idx = column.checked_sub(x)? / 4;
+        return letters.chars().nth(idx as usize).map(Click::Letter);
+    }
+
+    if below != 2 * rows.len() as u16 {
+        return None;
+    }
+
+    keyboard_buttons(cols, ascii)
+        .into_iter()
+        .find(|&(_, x, label)| (x..x + display_width(label) as u16).contains(&column))
+        .map(|(click, _, _)| click)
+}
+
+/// How many answers are left and the guesses expected to narrow them down the most.
+struct Suggestions {
+    left: usize,
+    guesses: Vec<(&'static str, f64)>,
+}
+
+fn suggestions(wordle: &Wordle) -> Suggestions {
+    let candidates = possible_answers(&wordle.feedback_history());
+
+    Suggestions {
+        left: candidates.len(),
+        guesses: solver::best_guesses(&ANSWERS, &candidates, ASSIST_SUGGESTIONS),
+    }
+}
+
+/// The other player in a race, a friend or the bot, as far as they have got.
+#[derive(Default)]
+struct Opponent {
+    rows: Vec<Vec<Clue>>,
+    /// Whether they won, once they are done.
+    won: Option<bool>,
+    left: bool,
+    /// Every clue the bot is going to get, shown a row at a time. Empty for a friend.
+    bot: Vec<Vec<Clue>>,
+}
+
+impl Opponent {
+    fn bot(wordle: &Wordle) -> Self {
+        let guesses = solver::solve_by_information(&wordle.answer, wordle.max_guesses);
+
+        Self {
+            bot: guesses
+                .iter()
+                .map(|guess| score_guess(&wordle.answer, guess))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Shows the bot's guesses made by `elapsed` into the game, a guess every `BOT_PACE`.
+    fn catch_up(&mut self, elapsed: Duration) {
+        if self.bot.is_empty() {
+            return;
+        }
+
+        let made = (elapsed.as_secs() / BOT_PACE.as_secs()) as usize;
+        self.rows = self.bot[..made.min(self.bot.len())].to_vec();
+
+        if made >= self.bot.len() {
+            self.won = Some(self.rows.last().is_some_and(|clues| is_win(clues)));
+        }
+    }
+
+    /// How the race went, as told once the game is over, having taken `guesses`
+    /// over `duration`.
+    fn verdict(&self, won: bool, guesses: usize, duration: Duration) -> String {
+        if !self.bot.is_empty() {
+            return self.bot_verdict(won, guesses, duration);
+        }
+
+        // against a friend, whoever solves the answer first wins
+        match self.won {
+            Some(true) => format!("Your friend solved it first, in {}", self.rows.len()),
+            _ if won => String::from("You won the race!"),
+            Some(false) => String::from("Neither of you solved it"),
+            None if self.left => String::from("Your friend left the race"),
+            None => format!("Your friend is still going after {}", self.rows.len()),
+        }
+    }
+
+    /// Against the bot the fewest guesses win, and a tie goes to whoever took
+    /// less time, the bot taking `BOT_PACE` over each guess.
+    fn bot_verdict(&self, won: bool, guesses: usize, duration: Duration) -> String {
+        let solved = self.bot.last().is_some_and(|clues| is_win(clues));
+        let bot_guesses = self.bot.len();
+        let bot_time = BOT_PACE * bot_guesses as u32;
+
+        let beat = match (won, solved) {
+            (false, false) => return String::from("Neither you nor the bot solved it"),
+            (true, false) => true,
+            (false, true) => false,
+            (true, true) => (guesses, duration) < (bot_guesses, bot_time),
+        };
+
+        let bot = if solved {
+            format!("the bot solved it in {bot_guesses}")
+        } else {
+            String::from("the bot didn't solve it")
+        };
+
+        if beat {
+            format!("You beat the bot, {bot}")
+        } else {
+            format!("The bot wins, {bot}")
+        }
+    }
+}
+
+/// Tells the other side of a race that the game is over, if there is one.
+/// They only miss out on the news if the connection is gone.
+fn send_done(race: &mut Option<Race>, won: bool) {
+    if let Some(race) = race {
+        let _ = race.send_done(won);
+    }
+}
+
+/// What the tutorial says beside the grid: how to guess before the first
+/// guess, and after that what each kind of clue the last guess got means,
+/// with the clue to color it by.
+fn tutorial_callout(wordle: &Wordle) -> Vec<(String, Option<Clue>)> {
+    let Some(row) = wordle.guesses().len().checked_sub(1) else {
+        return vec![
+            (
+                format!("Guess the hidden word in {} tries:", wordle.max_guesses),
+                None,
+            ),
+            (
+                format!("type any {}-letter word, then Enter", wordle.length()),
+                None,
+            ),
+        ];
+    };
+
+    let guess = &wordle.guesses()[row];
+
+    let Some(clues) = wordle.clues(row) else {
+        return Vec::new();
+    };
+
+    let mut lines = Vec::new();
+
+    // a grey copy of a letter found elsewhere in the guess only says how many
+    // copies there are, so it gets a line of its own
+    let found = |c: char| {
+        guess
+            .chars()
+            .zip(&clues)
+            .any(|(g, &clue)| g == c && clue != Clue::Grey)
+    };
+    let kinds = [
+        (Clue::Green, false),
+        (Clue::Yellow, false),
+        (Clue::Grey, false),
+        (Clue::Grey, true),
+    ];
+
+    for (kind, copy) in kinds {
+        let Some(c) = guess
+            .chars()
+            .zip(clues.iter().copied())
+            .find(|&(c, clue)| clue == kind && (kind != Clue::Grey || found(c) == copy))
+            .map(|(c, _)| upper(c))
+        else {
+            continue;
+        };
+
+        let line = match kind {
+            Clue::Green => format!("{c} is green: right letter, right spot"),
+            Clue::Yellow => format!("{c} is yellow: in the word, another spot"),
+            Clue::Grey if copy => format!("{c} is grey here: no more {c}s"),
+            Clue::Grey => format!("{c} is grey: not in the word at all"),
+        };
+
+        lines.push((line, Some(kind)));
+    }
+
+    lines.push((String::from("Use what you found in the next guess"), None));
+    lines
+}
+
+/// Writes every recorded game in `format` to `file`, or to stdout without one.
+fn export_stats(format: ExportFormat, file: Option<PathBuf>) -> Result<(), WordleError> {
+    let stats = Stats::load()?;
+
+    match (format, file) {
+        (ExportFormat::Csv, None) => stats.write_csv(std::io::stdout().lock())?,
+        (ExportFormat::Json, None) => stats.write_json(std::io::stdout().lock())?,
+        (ExportFormat::Csv, Some(path)) => stats.export_csv(&path)?,
+        (ExportFormat::Json, Some(path)) => stats.export_json(&path)?,
+    }
+
+    Ok(())
+}
+
+/// Whether the game is a race against a friend, hosted or joined.
+fn racing(args: &Args) -> bool {
+    matches!(
+        args.command,
+        Some(Command::Host { .. } | Command::Join { .. })
+    )
+}
+
+/// The first flag given that a race can't go with, since it picks or changes
+/// the answer both sides have to share, or plays another kind of game.
+fn race_conflict(args: &Args) -> Option<&'static str> {
+    let joining = matches!(args.command, Some(Command::Join { .. }));
+
+    [
+        ("--seed", joining && args.seed.is_some()),
+        ("--word", args.word.is_some()),
+        ("--theme-words", args.theme_words.is_some()),
+        ("--ban", args.ban.is_some()),
+        ("--length", args.length != 5),
+        ("--lang", args.lang != Language::English),
+        ("--absurdle", args.absurdle),
+        ("--daily", args.daily),
+        ("--friend", args.friend),
+        ("--resume", args.resume),
+        ("--review", args.review),
+        ("--boards", args.boards.is_some()),
+        ("--marathon", args.marathon),
+        ("--tutorial", args.tutorial),
+        ("--versus-bot", args.versus_bot),
+    ]
+    .into_iter()
+    .find_map(|(flag, given)| given.then_some(flag))
+}
+
+/// Whether this is the very first launch, with no stats yet, for a plain game
+/// that the tutorial can take the place of.
+fn first_launch(args: &Args, config: &Config) -> bool {
+    !config.hide_tips
+        && !args.deterministic
+        && !args.resume
+        && !args.friend
+        && !args.versus_bot
+        && args.seed.is_none()
+        && args.boards.is_none()
+        && !racing(args)
+        && args.mode == Mode::Classic
+        && picks_answer(args)
+        && !(args.candidates || args.assist || args.letter_frequency || args.practice)
+        && Stats::path().is_some_and(|path| !path.exists())
+}
+
+/// Transient state drawn around the board that isn't part of the game itself.
+#[derive(Default)]
+struct Hud {
+    /// Fraction of the row timer that is left.
+    timer: Option<f64>,
+    message: Option<String>,
+    paused: bool,
+    /// Opening tip shown to new players until the first keypress.
+    tip: bool,
+    /// Panel of the answers still possible, beside the grid.
+    candidates: bool,
+    /// Panel of how many of the answers still possible each letter is in,
+    /// beside the grid.
+    frequency: bool,
+    /// Panel of the answers left and the guesses suggested for them, beside
+    /// the grid, kept up to date after every guess while it is shown.
+    assist: Option<Suggestions>,
+    /// Answers left after each row, kept up to date after every guess.
+    remaining: Vec<usize>,
+    /// Daily streak that today's puzzle could break, shown until the first keypress.
+    streak: Option<usize>,
+    /// Brief notice shown in place of the message, such as why a guess was
+    /// rejected, and when it comes down.
+    toast: Option<(String, Instant)>,
+    /// Tiles of the last guess that turned up something new, while they flash,
+    /// and when they stop.
+    fresh: Option<(Vec<bool>, Instant)>,
+    /// Tiles of the last guess turned over so far, while it is being revealed.
+    revealed: Option<usize>,
+    /// Time spent on the game so far, with `--speedrun`.
+    clock: Option<Duration>,
+    /// The other player's clues, beside the grid, during a race.
+    opponent: Option<Opponent>,
+    /// Every key binding with what it does, while the help is shown over the grid.
+    help: Option<Vec<(String, &'static str)>>,
+    /// Time left until the next daily puzzle, while playing one.
+    next_daily: Option<Duration>,
+    /// What the status bar along the bottom says, unless it is hidden.
+    status: Option<GameContext>,
+    /// The run the game is part of, with `--marathon`.
+    marathon: Option<Marathon>,
+}
+
+/// The game as a whole, for the status bar, beyond what the board itself holds.
+struct GameContext {
+    /// The kind of game being played, such as `Daily 1234` or `Jotto`.
+    mode: String,
+    /// Games won in a row before this one.
+    streak: usize,
+    /// Time spent on the game so far, leaving out pauses.
+    elapsed: Duration,
+}
+
+/// Short name for the kind of game being played, for the status bar.
+fn mode_name(args: &Args, wordle: &Wordle) -> String {
+    if let Some(day) = wordle.day {
+        return format!("Daily {day}");
+    }
+
+    let name = game_mode(args, wordle);
+
+    // a marathon's seed is only that of its current answer
+    match wordle.seed.filter(|_| !args.marathon) {
+        Some(seed) => format!("{name} #{seed}"),
+        None => String::from(name),
+    }
+}
+
+/// The kind of game being played, without the day or seed that tell one game
+/// of it from another, as the stats keep it.
+fn game_mode(args: &Args, wordle: &Wordle) -> &'static str {
+    if wordle.day.is_some() {
+        "Daily"
+    } else if args.tutorial {
+        "Tutorial"
+    } else if args.marathon {
+        "Marathon"
+    } else if racing(args) {
+        "Race"
+    } else if args.boards.is_some() {
+        "Boards"
+    } else if args.absurdle {
+        "Absurdle"
+    } else if args.review {
+        "Review"
+    } else if args.versus_bot {
+        "Versus bot"
+    } else if args.friend {
+        "Friend"
+    } else if args.practice {
+        "Practice"
+    } else if args.mode == Mode::Jotto {
+        "Jotto"
+    } else if args.speedrun {
+        "Speedrun"
+    } else if args.row_timer.is_some() {
+        "Timed"
+    } else {
+        "Classic"
+    }
+}
+
+fn render_wordle(
+    screen: &mut Screen,
+    wordle: &Wordle,
+    args: &Args,
+    theme: &Theme,
+    hud: &Hud,
+) -> std::io::Result<()> {
+    let (cols, rows) = terminal::size()?;
+    let mut frame = Frame::new(cols, rows);
+
+    let (x, y) = grid_origin(args)?;
+    let width = grid_width(wordle.length());
+    let height = grid_height(wordle.max_guesses);
+
+    let [top, mid, int, bot] = args.border.unwrap_or_default().rows(wordle.length());
+
+    let rows = {
+        let mut rows: Vec<&str> = std::iter::repeat_n([&*mid, &*int], wordle.max_guesses)
+            .flatten()
+            .collect();
+        rows.pop();
+        rows.push(&bot);
+        rows.insert(0, &top);
+
+        rows
+    };
+
+    // print grid
+    for (y, row) in (y..).zip(&rows) {
+        frame.print(x, y, row.with(theme.border));
+    }
+
+    // a paused game shows nothing but the empty grid, so that nobody can peek
+    if hud.paused && hud.help.is_none() {
+        let label = "  PAUSED  ";
+        let x = x + (width - label.len() as u16) / 2;
+        frame.print(x, y + height / 2, label.bold().reverse());
+
+        let hint = "Press any key to resume";
+        let x = (x + label.len() as u16 / 2).saturating_sub(hint.len() as u16 / 2);
+        frame.print(x, y + height + 1, hint.with(theme.grey));
+
+        return screen.draw(frame);
+    }
 
-fn main() -> std::io::Result<()> {
-    std::panic::set_hook(Box::new(|info| {
-        let _ = terminal::disable_raw_mode();
-        let _ = execute!(std::io::stdout(), LeaveAlternateScreen, Show);
-        println!("thread {info}");
-    }));
+    // print row timer over the border above the current guess
+    if let (Some(left), None) = (hud.timer, wordle.won()) {
+        let row = rows[2 * wordle.guesses().len()];
+        let filled = (left * width as f64).ceil() as usize;
+        let color = if left > 0.25 {
+            theme.timer
+        } else {
+            theme.warning
+        };
+        let y = y + 2 * wordle.guesses().len() as u16;
 
-    let mut stdout = std::io::stdout();
+        for (idx, c) in row.chars().enumerate() {
+            let color = if idx < filled { color } else { theme.grey };
+            frame.print(x + idx as u16, y, c.with(color));
+        }
+    }
+
+    // only the row being typed in stands out, until the game is over
+    let focus = args.focus && wordle.won().is_none();
 
-    terminal::enable_raw_mode()?;
-    execute!(stdout, EnterAlternateScreen, Hide)?;
+    // print previous guesses
+    for (row, (y, guess)) in (y + 1..).step_by(2).zip(wordle.guesses()).enumerate() {
+        let last = row + 1 == wordle.guesses().len();
 
-    let mut wordle = Wordle::new();
+        if guess.is_empty() {
+            let burned = if args.ascii { 'x' } else { '×' };
 
-    let won = loop {
-        render_wordle(&wordle)?;
+            for x in (x + 2..).step_by(4).take(wordle.length()) {
+                frame.print(x, y, burned.with(theme.warning));
+            }
 
-        if let Some(won) = wordle.won() {
-            std::thread::sleep(Duration::from_secs(1));
-            break won;
+            continue;
         }
 
-        match event::read()? {
-            Event::Key(KeyEvent {
-                code: KeyCode::Esc, ..
-            }) => break false,
+        let clues = match args.mode {
+            Mode::Classic => wordle.clues(row).unwrap(),
 
-            Event::Key(KeyEvent {
-                code: KeyCode::Char(c),
-                ..
-            }) if c.is_ascii_alphabetic() => {
-                wordle.input(c);
+            // letters stay uncolored, with only the count beside the row
+            Mode::Jotto => {
+                let common = letters_in_common(&wordle.answer, guess);
+                frame.print(x + width + 1, y, common.to_string().stylize());
+
+                let clue = if *guess == wordle.answer {
+                    Clue::Green
+                } else {
+                    Clue::Grey
+                };
+                vec![clue; wordle.length()]
             }
+        };
 
-            Event::Key(KeyEvent {
-                code: KeyCode::Backspace,
-                ..
-            }) => {
-                wordle.erase();
+        let tint = match args.mode {
+            Mode::Classic if args.warmth => theme.tint(closeness(&clues)),
+            _ => None,
+        };
+
+        for (idx, c) in guess.chars().enumerate() {
+            let x = 4 * idx as u16 + x + 2;
+            let color = match (args.mode, clues[idx]) {
+                (Mode::Jotto, Clue::Grey) => theme.cursor,
+                (_, clue) => theme.clue(clue),
+            };
+
+            // tiles not turned over yet look like the guess being typed, the
+            // one being turned fading out before it shows its color
+            if let Some(revealed) = hud.revealed.filter(|&revealed| last && idx >= revealed) {
+                let tile = format!(" {} ", upper(c)).with(theme.cursor);
+                frame.print(x - 1, y, if idx == revealed { tile.dim() } else { tile });
+                continue;
             }
 
-            Event::Key(KeyEvent {
-                code: KeyCode::Enter,
-                ..
-            }) => {
-                wordle.guess();
+            let mut tile = theme.tile(c, clues[idx]).with(color);
+
+            // dim rather than bold, as terminals don't agree on showing both
+            tile = if focus { tile.dim() } else { tile.bold() };
+
+            if let Some(tint) = tint {
+                tile = tile.on(tint);
             }
 
-            _ => {}
+            if hud
+                .fresh
+                .as_ref()
+                .is_some_and(|(fresh, _)| last && fresh[idx])
+            {
+                tile = tile.reverse();
+            }
+
+            frame.print(x - 1, y, tile);
         }
+    }
+
+    // print current guess
+    let color = if args.check_prefix && !wordle.prefix_is_viable() {
+        theme.warning
+    } else {
+        theme.cursor
     };
 
-    terminal::disable_raw_mode()?;
-    execute!(stdout, LeaveAlternateScreen, Show)?;
+    // what is already known about the letters typed, with --input-hints
+    let hints = match args.mode {
+        Mode::Classic if args.input_hints => wordle.input_clues(),
+        _ => Vec::new(),
+    };
 
-    if won {
-        println!("🦀🦀🦀 You have won!!! 🦀🦀🦀");
-    } else {
-        println!("The answer was {}.", wordle.answer.to_ascii_uppercase());
-        println!("Maybe try again later...");
+    for (idx, (x, c)) in (x + 2..).step_by(4).zip(wordle.curr.chars()).enumerate() {
+        let y = y + 2 * wordle.guesses().len() as u16 + 1;
+
+        if idx < wordle.locked_len() {
+            frame.print(x, y, upper(c).with(theme.green).bold());
+            continue;
+        }
+
+        let color = match hints.get(idx).copied().flatten() {
+            Some(clue) => theme.clue(clue),
+            None => color,
+        };
+
+        let c = shown_letter(c, args.blind);
+        if focus {
+            frame.print(x, y, c.with(color).bold());
+        } else {
+            frame.print(x, y, c.with(color));
+        }
     }
 
-    Ok(())
+    // print the letters pinned down so far, faintly, in the cells not typed over yet
+    if args.ghosts && args.mode == Mode::Classic && wordle.won().is_none() {
+        let y = y + 2 * wordle.guesses().len() as u16 + 1;
+        let ghosts = wordle.position_candidates().into_iter().enumerate();
+
+        for (idx, letters) in ghosts.skip(letter_count(&wordle.curr)) {
+            if let (Some(&c), 1) = (letters.first(), letters.len()) {
+                let x = 4 * idx as u16 + x + 2;
+                frame.print(x, y, upper(c).with(theme.green).dim());
+            }
+        }
+    }
+
+    // print row numbers colored by how much each guess found
+    if args.annotate {
+        for (row, y) in (1..=wordle.max_guesses).zip((y + 1..).step_by(2)) {
+            let color = match wordle.guesses().get(row - 1) {
+                None => {
+                    frame.print(x.saturating_sub(2), y, row.to_string().dim());
+                    continue;
+                }
+                Some(guess) if guess.is_empty() => theme.warning,
+                Some(guess) => match letters_in_common(&wordle.answer, guess) {
+                    3.. => theme.green,
+                    1..=2 => theme.yellow,
+                    _ => theme.grey,
+                },
+            };
+
+            frame.print(x.saturating_sub(2), y, row.to_string().with(color).bold());
+        }
+    }
+
+    // print the answers left after each row to the left of the grid
+    if (args.analyze_live || args.practice) && args.mode == Mode::Classic {
+        let x = x.saturating_sub(if args.annotate { 8 } else { 6 });
+        frame.print(x, y, " left".dim());
+
+        for (y, count) in (y + 1..).step_by(2).zip(&hud.remaining) {
+            frame.print(x, y, format!("{count:>5}").with(theme.cursor));
+        }
+    }
+
+    // print the answers still possible to the right of the grid
+    let few_left = args.practice
+        && hud
+            .remaining
+            .last()
+            .is_some_and(|&left| left <= PRACTICE_LIST);
+
+    let other_panel = hud.assist.is_some() || hud.frequency;
+
+    if (hud.candidates || few_left) && !other_panel && args.mode == Mode::Classic {
+        let candidates = possible_answers(&wordle.feedback_history());
+        let shown = 10;
+        let x = x + width + 2;
+
+        frame.print(x, y + 1, format!("{} left", candidates.len()).bold());
+
+        for (y, word) in (y + 3..).zip(candidates.iter().take(shown)) {
+            frame.print(x, y, word.to_uppercase().stylize());
+        }
+
+        if candidates.len() > shown {
+            frame.print(x, y + 3 + shown as u16, "…".stylize());
+        }
+    }
+
+    // print how many of the answers left each letter is in to the right of the
+    // grid, in columns as long as the grid, the warmest the most common
+    if hud.frequency && args.mode == Mode::Classic {
+        let candidates = possible_answers(&wordle.feedback_history());
+        let mut counts: Vec<(char, usize)> =
+            solver::letter_counts(&candidates).into_iter().collect();
+        counts.sort_by_key(|&(c, count)| (std::cmp::Reverse(count), c));
+
+        let x = x + width + 2;
+        let rows = usize::from(height.saturating_sub(3)).max(1);
+
+        frame.print(x, y + 1, format!("{} left", candidates.len()).bold());
+
+        for (idx, (c, count)) in counts.into_iter().enumerate() {
+            let share = count as f32 / candidates.len() as f32;
+            let line = format!("{} {:>3}%", upper(c), (100.0 * share).round());
+            let mut line = line.stylize();
+
+            if let Some(tint) = theme.tint(share) {
+                line = line.on(tint);
+            }
+
+            let (column, row) = (idx / rows, idx % rows);
+            frame.print(x + 8 * column as u16, y + 3 + row as u16, line);
+        }
+    }
+
+    // print the solver's suggestions to the right of the grid
+    if let (Some(assist), Mode::Classic) = (&hud.assist, args.mode) {
+        let x = x + width + 2;
+
+        frame.print(x, y + 1, format!("{} left", assist.left).bold());
+        frame.print(x, y + 3, "Try".with(theme.grey));
+
+        for (y, (guess, bits)) in (y + 4..).zip(&assist.guesses) {
+            let line = format!("{} {bits:.2} bits", guess.to_uppercase());
+            frame.print(x, y, line.stylize());
+        }
+    }
+
+    // print the other player's clues, without their letters, to the left of the grid
+    if let Some(opponent) = &hud.opponent {
+        let live = args.analyze_live || args.practice;
+        let x = x.saturating_sub(if live { 20 } else { 14 });
+        let status = match opponent.won {
+            Some(true) => "Solved!",
+            Some(false) => "Out",
+            None if opponent.left => "Gone",
+            None if !opponent.bot.is_empty() => "Bot",
+            None => "Friend",
+        };
+        frame.print(x, y, status.bold());
+
+        for (y, clues) in (y + 1..).step_by(2).zip(&opponent.rows) {
+            for (x, &clue) in (x..).step_by(2).zip(clues) {
+                let symbol = if theme.symbols { clue.ascii() } else { '■' };
+                frame.print(x, y, symbol.with(theme.clue(clue)));
+            }
+        }
+    }
+
+    // print the time taken so far beside the top of the grid
+    if let Some(clock) = hud.clock {
+        frame.print(x + width + 2, y, fmt_clock(clock).with(theme.timer).bold());
+    }
+
+    // print how the marathon run is going above the grid
+    if let Some(run) = hud.marathon {
+        let line = format!("{} solved · {} points", run.solved, run.score);
+        let x = (x + width / 2).saturating_sub(display_width(&line) as u16 / 2);
+        frame.print(x, y.saturating_sub(1), line.with(theme.yellow).bold());
+    }
+
+    // print the streak at stake above the grid
+    if let Some(streak) = hud.streak {
+        let line = format!("🔥 {streak}-day streak on the line");
+        let x = (x + width / 2).saturating_sub(display_width(&line) as u16 / 2);
+        frame.print(x, y.saturating_sub(1), line.with(theme.yellow).bold());
+    }
+
+    // print what the clues of the last guess mean beside it, in the tutorial
+    if args.tutorial && wordle.won().is_none() {
+        let row = wordle.guesses().len().saturating_sub(1) as u16;
+        let arrow = if args.ascii { "<" } else { "◀" };
+
+        for (idx, (line, clue)) in tutorial_callout(wordle).into_iter().enumerate() {
+            let lead = if idx == 0 { arrow } else { " " };
+            let color = clue.map_or(theme.cursor, |clue| theme.clue(clue));
+            let y = y + 2 * row + 1 + idx as u16;
+            frame.print(x + width + 1, y, format!("{lead} {line}").with(color));
+        }
+    }
+
+    // print opening tip over the grid
+    if hud.tip {
+        let lines = [
+            "",
+            "Tip: open with a word full of",
+            "common letters like E, A, R,",
+            "T and S, without repeats.",
+            "",
+            "Press any key to start",
+            "",
+        ];
+
+        let width = lines
+            .iter()
+            .map(|line| display_width(line))
+            .max()
+            .unwrap_or(0)
+            + 4;
+        let x = (x + grid_width(wordle.length()) / 2).saturating_sub(width as u16 / 2);
+
+        for (y, line) in (y + 3..).zip(lines) {
+            frame.print(x, y, format!("{line:^width$}").reverse());
+        }
+    }
+
+    // the keyboard would give away positional clues that jotto keeps hidden
+    if shows_keyboard(args) && args.mode == Mode::Classic {
+        let layout = args.layout.unwrap_or_default();
+        render_keyboard(
+            &mut frame,
+            wordle,
+            theme,
+            layout,
+            y + height + 3,
+            args.advanced_keyboard,
+        );
+
+        if args.mouse {
+            let y = y + height + 3 + 2 * layout.rows().len() as u16;
+
+            for (_, x, label) in keyboard_buttons(frame.width(), args.ascii) {
+                frame.print(x, y, label.reverse().bold());
+            }
+        }
+    }
+
+    // print the countdown to the next daily puzzle along the bottom of the screen,
+    // at the end of the status bar when there is one
+    let countdown = hud.next_daily.map(|left| {
+        let secs = left.as_secs();
+        format!(
+            "Next puzzle in {}:{:02}:{:02}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )
+    });
+
+    match (&hud.status, countdown) {
+        (Some(status), countdown) => render_status_bar(
+            &mut frame,
+            status,
+            wordle,
+            countdown.as_deref(),
+            args.ascii,
+            theme,
+        ),
+        (None, Some(line)) => {
+            let x = (frame.width() / 2).saturating_sub(line.len() as u16 / 2);
+            frame.print(x, frame.height().saturating_sub(1), line.with(theme.grey));
+        }
+        (None, None) => {}
+    }
+
+    // print message below the grid
+    match &hud.toast {
+        Some((toast, _)) => {
+            let toast = format!(" {toast} ");
+            let x = (x + width / 2).saturating_sub(display_width(&toast) as u16 / 2);
+            frame.print(x, y + height + 1, toast.bold().reverse());
+        }
+        None => {
+            let message = hud.message.as_deref().unwrap_or_default();
+            let x = (x + width / 2).saturating_sub(display_width(message) as u16 / 2);
+            frame.print(x, y + height + 1, message.stylize());
+        }
+    }
+
+    if let Some(bindings) = &hud.help {
+        render_help(&mut frame, bindings, wordle.max_guesses, args.ascii, theme);
+    }
+
+    screen.draw(frame)
 }
 
-fn render_wordle(wordle: &Wordle) -> std::io::Result<()> {
-    let (cols, rows) = terminal::size()?;
-    let (width, height) = (21, 13);
-    let (x, y) = ((cols - width) / 2, (rows - height) / 2);
+/// Draws a bar along the bottom of the screen with the kind of game, the guesses
+/// made, hard mode, the time taken and the streak, then `right` at its far end.
+fn render_status_bar(
+    frame: &mut Frame,
+    status: &GameContext,
+    wordle: &Wordle,
+    right: Option<&str>,
+    ascii: bool,
+    theme: &Theme,
+) {
+    let mut parts = vec![
+        status.mode.clone(),
+        format!("{}/{}", wordle.guesses().len(), wordle.max_guesses),
+    ];
 
-    let top = "╔═══╦═══╦═══╦═══╦═══╗";
-    let mid = "║   ║   ║   ║   ║   ║";
-    let int = "╠═══╬═══╬═══╬═══╬═══╣";
-    let bot = "╚═══╩═══╩═══╩═══╩═══╝";
+    if wordle.ultra_hard {
+        parts.push(String::from("Ultra hard"));
+    } else if wordle.hard {
+        parts.push(String::from("Hard"));
+    }
 
-    let mut stdout = std::io::stdout();
+    parts.push(fmt_clock(status.elapsed));
+    parts.push(format!("Streak {}", status.streak));
 
-    let rows = {
-        let mut rows: Vec<&str> = std::iter::repeat([mid, int]).take(6).flatten().collect();
-        rows.pop();
-        rows.push(bot);
-        rows.insert(0, top);
+    let left = format!(" {} ", parts.join(if ascii { " | " } else { " │ " }));
+    let right = right.map(|right| format!("{right} ")).unwrap_or_default();
+    let width = usize::from(frame.width());
+    let gap = width.saturating_sub(display_width(&left) + display_width(&right));
 
-        rows
+    let line = if gap > 0 {
+        format!("{left}{}{right}", " ".repeat(gap))
+    } else {
+        format!("{left:width$}")
     };
 
-    // print grid
-    for (y, row) in (y..).zip(rows) {
-        queue!(stdout, MoveTo(x, y), Print(row))?;
+    frame.print(
+        0,
+        frame.height().saturating_sub(1),
+        line.with(theme.grey).reverse(),
+    );
+}
+
+/// Draws a box in the middle of the screen with the rules, what each color
+/// means, and the keys.
+fn render_help(
+    frame: &mut Frame,
+    bindings: &[(String, &str)],
+    guesses: usize,
+    ascii: bool,
+    theme: &Theme,
+) {
+    let legend = [
+        (Clue::Green, "in the word, in that spot"),
+        (Clue::Yellow, "in the word, but elsewhere"),
+        (Clue::Grey, "not in the word"),
+    ];
+    let keys: Vec<String> = bindings
+        .chunks(2)
+        .map(|pair| {
+            pair.iter()
+                .map(|(key, label)| format!("{key:>9}  {label:<15}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+        })
+        .collect();
+
+    let mut lines = vec![
+        format!("Find the hidden word in {guesses} guesses."),
+        format!(
+            "Every guess must be a {}-letter word.",
+            wordle::word_length()
+        ),
+        String::new(),
+    ];
+    let legend_at = lines.len();
+    lines.extend(legend.iter().map(|(_, meaning)| format!("     {meaning}")));
+    lines.push(String::new());
+    lines.extend(keys);
+    lines.push(String::new());
+    lines.push(String::from("Press any key to go back"));
+
+    let width = lines
+        .iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or(0) as u16
+        + 4;
+    let height = lines.len() as u16 + 2;
+    let x = frame.width().saturating_sub(width) / 2;
+    let y = frame.height().saturating_sub(height) / 2;
+
+    let [top_left, top_right, bottom_left, bottom_right, across, down] = if ascii {
+        ["+", "+", "+", "+", "-", "|"]
+    } else {
+        ["┌", "┐", "└", "┘", "─", "│"]
+    };
+    let across = across.repeat(width as usize - 2);
+    let top = format!("{top_left}{across}{top_right}");
+    let bottom = format!("{bottom_left}{across}{bottom_right}");
+    frame.print(x, y, top.with(theme.border));
+    frame.print(x, y + height - 1, bottom.with(theme.border));
+
+    for (y, line) in (y + 1..).zip(&lines) {
+        let inner = width as usize - 4;
+        frame.print(x, y, down.with(theme.border));
+        frame.print(x + 1, y, format!(" {line:<inner$} ").stylize());
+        frame.print(x + width - 1, y, down.with(theme.border));
     }
 
-    // print previous guesses
-    for (y, guess) in (y + 1..).step_by(2).zip(&wordle.guesses) {
-        let mut colors = [Color::DarkGrey; 5];
-        let mut answer_chars: Vec<char> = wordle.answer.chars().collect();
+    let title = " Help ";
+    frame.print(x + 2, y, title.bold());
 
-        let guess_chars: Vec<char> = guess.chars().collect();
+    for (y, (clue, _)) in (y + 1 + legend_at as u16..).zip(legend) {
+        let tile = theme.tile('a', clue);
+        frame.print(x + 2, y, tile.with(theme.clue(clue)).bold().reverse());
+    }
+}
 
-        for idx in 0..5 {
-            if Some(guess_chars[idx]) == wordle.answer.chars().nth(idx) {
-                colors[idx] = Color::Green;
+/// Plays `--boards`, with its own loop as only typing and submitting apply,
+/// then prints the answers once `terminal_guard` has handed the terminal back.
+fn play_boards(
+    args: &Args,
+    count: usize,
+    theme: &Theme,
+    config: &Config,
+    terminal_guard: TerminalGuard,
+) -> std::io::Result<ExitCode> {
+    let seed = args.seed.unwrap_or_else(rand::random);
+    let mut boards = Boards::with_seed(count, seed, args.allow_rude);
+    let mut screen = Screen::default();
+    let clock = Stopwatch::start();
+    let recorder = StatsRecorder::default();
+    let mut observers = game_observers(args, Some(&recorder));
+    let mut toast: Option<(String, Instant)> = None;
 
-                answer_chars.remove(
-                    answer_chars
-                        .iter()
-                        .position(|&ch| ch == guess_chars[idx])
-                        .unwrap(),
-                );
+    loop {
+        toast = toast.filter(|(_, until)| *until > Instant::now());
+
+        draw_retrying(args, &mut screen, |screen| {
+            render_boards(
+                screen,
+                &boards,
+                args,
+                theme,
+                toast.as_ref().map(|(toast, _)| toast.as_str()),
+            )
+        })?;
+
+        if let Some(won) = boards.won() {
+            ring_bell(args, if won { 2 } else { 3 })?;
+            std::thread::sleep(Duration::from_secs(1));
+            break;
+        }
+
+        // wake up to take the toast down once its time is up
+        if let Some((_, until)) = &toast {
+            if !event::poll(until.saturating_duration_since(Instant::now()))? {
+                continue;
             }
         }
 
-        for (idx, c) in guess.chars().enumerate() {
-            if colors[idx] == Color::Green {
+        let code = match event::read()? {
+            Event::Key(KeyEvent { code, .. }) => code,
+
+            Event::Paste(text) => {
+                text.chars().for_each(|c| boards.input(c));
                 continue;
             }
 
-            if let Some(pos) = answer_chars.iter().position(|&ch| ch == c) {
-                colors[idx] = Color::Yellow;
-                answer_chars.remove(pos);
+            Event::Resize(..) => {
+                let fits = wait_for_size(args, config)?;
+                screen.invalidate();
+
+                if !fits {
+                    break;
+                }
+
+                continue;
+            }
+
+            _ => continue,
+        };
+
+        match config.keys.action(code) {
+            Some(Action::Quit) => break,
+
+            Some(Action::Erase) => boards.erase(),
+
+            Some(Action::Submit) => {
+                let result = boards.guess();
+
+                if let Some(why) = rejection_message(&result) {
+                    toast = Some((why, Instant::now() + TOAST_TIME));
+                    ring_bell(args, 1)?;
+                }
+            }
+
+            Some(_) => {}
+
+            None => {
+                if let KeyCode::Char(c) = code {
+                    boards.input(c);
+                }
             }
         }
+    }
 
-        for (idx, c) in guess.chars().enumerate() {
-            let x = 4 * idx as u16 + x + 2;
+    drop(terminal_guard);
+
+    // each board is a game of its own in the stats, once they are all over
+    let mut unlocked = Vec::new();
 
-            queue!(
-                stdout,
-                MoveTo(x, y),
-                PrintStyledContent(StyledContent::new(
-                    ContentStyle {
-                        foreground_color: Some(colors[idx]),
-                        ..Default::default()
-                    },
-                    c.to_ascii_uppercase().bold(),
-                ))
-            )?;
+    if boards.won().is_some() {
+        for board in &boards.boards {
+            recorder.ending(args, board, clock.elapsed());
+            observers.on_game_end(board);
+            unlocked.extend(recorder.take_unlocked());
         }
     }
 
-    // print current guess
-    for (x, c) in (x + 2..).step_by(4).zip(wordle.curr.chars()) {
-        let y = y + 2 * wordle.guesses.len() as u16 + 1;
-        queue!(stdout, MoveTo(x, y), Print(c.to_ascii_uppercase()))?;
+    for (board, solved) in boards.boards.iter().zip(boards.solved_in()) {
+        let answer = board.answer.to_uppercase();
+
+        match solved {
+            Some(guesses) => println!("{answer}: solved in {guesses}"),
+            None => println!("{answer}: not solved"),
+        }
     }
 
-    stdout.flush()?;
-    Ok(())
-}
+    match boards.won() {
+        Some(true) => println!("All {count} boards in {} guesses!", boards.guesses()),
+        Some(false) => println!("Out of guesses"),
+        None => {}
+    }
 
-struct Wordle {
-    answer: String,
-    curr: String,
-    guesses: Vec<String>,
-}
+    println!("Replay these boards with --boards {count} --seed {seed}");
 
-lazy_static! {
-    static ref GUESSES: HashSet<&'static str> = include_str!("../guesses").lines().collect();
-    static ref ANSWERS: Vec<&'static str> = include_str!("../answers").lines().collect();
+    for achievement in unlocked {
+        println!("{}", unlocked_message(achievement));
+    }
+
+    Ok(ExitCode::SUCCESS)
 }
 
-impl Wordle {
-    fn new() -> Self {
-        let answer = ANSWERS.choose(&mut rand::thread_rng()).unwrap();
+/// Draws the boards side by side, with the guess being typed on every board
+/// that is still being played and how long each solved one took below it.
+fn render_boards(
+    screen: &mut Screen,
+    boards: &Boards,
+    args: &Args,
+    theme: &Theme,
+    toast: Option<&str>,
+) -> std::io::Result<()> {
+    let (cols, rows) = terminal::size()?;
+    let mut frame = Frame::new(cols, rows);
 
-        Self {
-            answer: answer.to_string(),
-            curr: String::new(),
-            guesses: Vec::new(),
+    let (width, height) = layout_size(args);
+    let x = cols.saturating_sub(width) / 2;
+    let y = rows.saturating_sub(height) / 2;
+
+    let length = boards.boards[0].length();
+    let board_width = grid_width(length);
+    let [top, mid, int, bot] = args.border.unwrap_or_default().rows(length);
+
+    for ((x, board), solved) in (x..)
+        .step_by(usize::from(board_width) + 2)
+        .zip(&boards.boards)
+        .zip(boards.solved_in())
+    {
+        let mut grid: Vec<&str> = std::iter::repeat_n([&*mid, &*int], board.max_guesses)
+            .flatten()
+            .collect();
+        grid.pop();
+        grid.push(&bot);
+        grid.insert(0, &top);
+
+        for (y, row) in (y..).zip(&grid) {
+            frame.print(x, y, row.with(theme.border));
+        }
+
+        for (y, guess) in (y + 1..).step_by(2).zip(board.guesses()) {
+            let clues = score_guess(&board.answer, guess);
+
+            for ((x, c), clue) in (x + 1..).step_by(4).zip(guess.chars()).zip(clues) {
+                frame.print(x, y, theme.tile(c, clue).with(theme.clue(clue)).bold());
+            }
+        }
+
+        let below = y + grid.len() as u16;
+
+        match solved {
+            Some(guesses) => {
+                let label = format!("solved in {guesses}");
+                let x = x + (board_width - label.len() as u16) / 2;
+                frame.print(x, below, label.with(theme.green));
+            }
+
+            None if board.won().is_none() => {
+                let y = y + 2 * board.guesses().len() as u16 + 1;
+
+                for (x, c) in (x + 2..).step_by(4).zip(boards.curr.chars()) {
+                    frame.print(x, y, shown_letter(c, args.blind).with(theme.cursor));
+                }
+            }
+
+            None => {
+                let answer = board.answer.to_uppercase();
+                let x = x + (board_width - length as u16) / 2;
+                frame.print(x, below, answer.with(theme.warning).bold());
+            }
         }
     }
 
-    fn input(&mut self, c: char) {
-        if self.curr.len() < 5 {
-            self.curr.push(c.to_ascii_lowercase());
+    if let Some(toast) = toast {
+        let toast = format!(" {toast} ");
+        let x = (cols / 2).saturating_sub(display_width(&toast) as u16 / 2);
+        frame.print(x, y + height - 1, toast.bold().reverse());
+    }
+
+    screen.draw(frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn args(flags: &[&str]) -> Args {
+        Args::parse_from(std::iter::once("wordle").chain(flags.iter().copied()))
+    }
+
+    #[test]
+    fn praise_goes_by_guesses() {
+        let praised: Vec<&str> = (1..=6).map(praise).collect();
+        assert_eq!(
+            praised,
+            [
+                "Genius",
+                "Magnificent",
+                "Impressive",
+                "Splendid",
+                "Great",
+                "Phew"
+            ]
+        );
+
+        // games given more guesses than usual still scrape through
+        assert_eq!(praise(9), "Phew");
+    }
+
+    #[test]
+    fn batch_play_wins_only_with_the_answer() {
+        let args = args(&["--word", "crane"]);
+
+        let won = play_batch(
+            &args,
+            Cursor::new(
+                "slate
+
+notaword
+crane
+",
+            ),
+        )
+        .unwrap();
+        assert_eq!(won, ExitCode::SUCCESS);
+
+        let lost = play_batch(
+            &args,
+            Cursor::new(
+                "slate
+moist
+",
+            ),
+        )
+        .unwrap();
+        assert_eq!(lost, ExitCode::FAILURE);
+    }
+
+    #[test]
+    fn batch_games_have_different_answers() {
+        let args = args(&["--seed", "5", "--games", "3"]);
+        let mut played = HashSet::new();
+
+        for n in 0..3 {
+            let wordle = batch_game(&args, n, &played);
+            assert!(played.insert(wordle.answer));
         }
     }
 
-    fn erase(&mut self) {
-        self.curr.pop();
+    #[test]
+    fn theme_words_only_pick_from_the_category() {
+        let args = args(&["--theme-words", "color", "--seed", "3"]);
+
+        assert!(answer_pool(&args)
+            .iter()
+            .all(|answer| wordle::category(answer) == Some("color")));
+        assert_eq!(wordle::category(&new_game(&args).answer), Some("color"));
+    }
+
+    #[test]
+    fn wordle_opts_come_before_the_command_line() {
+        let cli = ["wordle", "--max-guesses", "4"].map(OsString::from);
+        let args = Args::parse_from(with_opts("--hard  --max-guesses 8", cli.into_iter()));
+
+        assert!(args.hard);
+        assert_eq!(args.max_guesses, 4);
+
+        let cli = ["wordle"].map(OsString::from);
+        let args = Args::parse_from(with_opts("", cli.into_iter()));
+        assert!(!args.hard);
+        assert_eq!(args.max_guesses, 6);
+    }
+
+    #[test]
+    fn end_message_shortens_on_narrow_terminals() {
+        assert_eq!(
+            end_message(true, "crane", 80, false),
+            "🦀🦀🦀 You have won!!! 🦀🦀🦀\n"
+        );
+        assert_eq!(end_message(true, "crane", 20, false), "🦀 You won! 🦀\n");
+        assert_eq!(
+            end_message(false, "crane", 80, false),
+            "The answer was CRANE.\nMaybe try again later...\n"
+        );
+        assert_eq!(
+            end_message(false, "crane", 20, false),
+            "Answer: CRANE\nTry again later\n"
+        );
+        assert_eq!(end_message(true, "crane", 20, true), "You won!\n");
+    }
+
+    #[test]
+    fn deterministic_runs_pick_the_same_answer() {
+        let mut first = args(&["--deterministic", "--row-timer", "30"]);
+        make_deterministic(&mut first);
+        assert_eq!(first.seed, Some(DEMO_SEED));
+        assert_eq!(first.row_timer, None);
+
+        let mut second = args(&["--deterministic"]);
+        make_deterministic(&mut second);
+        assert_eq!(new_game(&first).answer, new_game(&second).answer);
+
+        let mut seeded = args(&["--deterministic", "--seed", "7"]);
+        make_deterministic(&mut seeded);
+        assert_eq!(seeded.seed, Some(7));
+    }
+
+    #[test]
+    fn schedules_only_replace_the_days_they_list() {
+        let today = chrono::Local::now().date_naive();
+        let mut daily = args(&["--daily"]);
+
+        daily.schedule = Some(Schedule::parse(&format!("{today}\tcrane\n")).unwrap());
+        assert_eq!(new_game(&daily).answer, "crane");
+
+        // a day the schedule leaves out gets the usual daily word
+        let yesterday = today.pred_opt().unwrap();
+        daily.schedule = Some(Schedule::parse(&format!("{yesterday}\tcrane\n")).unwrap());
+        assert_eq!(
+            new_game(&daily).answer,
+            Wordle::daily(today, None, false).answer
+        );
+    }
+
+    #[test]
+    fn verify_accepts_only_real_wins() {
+        let args = args(&["--seed", "5"]);
+        let claim = |guesses: &[&str]| {
+            let guesses: Vec<String> = guesses.iter().map(|guess| guess.to_string()).collect();
+            verify(&args, &guesses)
+        };
+
+        assert_eq!(claim(&["slate", "FORGE"]), ExitCode::SUCCESS);
+        assert_eq!(claim(&["slate"]), ExitCode::FAILURE);
+        assert_eq!(claim(&["qqqqq", "forge"]), ExitCode::FAILURE);
+        assert_eq!(claim(&["forge", "slate"]), ExitCode::FAILURE);
+    }
+
+    #[test]
+    fn banned_letters_keep_answers_out_of_the_pool() {
+        let random = args(&["--ban", "eas"]);
+
+        assert!(answer_pool(&random)
+            .iter()
+            .all(|answer| !answer.contains(['e', 'a', 's'])));
+        assert!(!answer_is_banned(&random, &new_game(&random)));
+
+        let picked = args(&["--ban", "e", "--word", "crane"]);
+        assert!(answer_is_banned(&picked, &new_game(&picked)));
+    }
+
+    #[test]
+    fn coach_lists_a_few_answers_one_letter_away() {
+        assert_eq!(
+            neighbors_line("actor"),
+            "No other answer is one letter away, a truly unique word."
+        );
+        assert_eq!(neighbors_line("nymph"), "One letter away: LYMPH");
+        assert!(neighbors_line("share").ends_with(", …"));
+        assert_eq!(
+            neighbors_line("share").matches(", ").count(),
+            COACH_NEIGHBORS
+        );
+    }
+
+    #[test]
+    fn friend_secrets_have_to_be_guessable() {
+        let plain = args(&[]);
+        assert_eq!(check_secret(&plain, "crane"), Ok(()));
+        assert_eq!(
+            check_secret(&plain, "cranes"),
+            Err(String::from("The answer needs 5 letters"))
+        );
+        assert_eq!(
+            check_secret(&plain, "qqqqq"),
+            Err(String::from("QQQQQ isn't a word"))
+        );
+        assert_eq!(
+            check_secret(&args(&["--answers-only"]), "aahed"),
+            Err(String::from("AAHED isn't allowed as a guess"))
+        );
+        assert_eq!(
+            check_secret(&args(&["--ban", "e"]), "crane"),
+            Err(String::from("CRANE has a banned letter"))
+        );
+    }
+
+    /// A logged game named `name`, as read back from `--log`.
+    fn logged(name: &str, answer: &str, guesses: &[&str]) -> (String, BatchLog) {
+        let guesses: Vec<String> = guesses
+            .iter()
+            .map(|word| format!(r#"{{"word":"{word}","feedback":"?"}}"#))
+            .collect();
+        let json = format!(
+            r#"{{"answer":"{answer}","won":false,"seed":null,"guesses":[{}],"rejected":[]}}"#,
+            guesses.join(",")
+        );
+
+        (name.to_string(), serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn compare_lines_up_both_games() {
+        let alice = logged("alice", "crane", &["slate", "crane"]);
+        let bob = logged("bob", "crane", &["slate", "trace", "crane"]);
+
+        let table = [
+            "   alice        bob",
+            " 1 SLATE BBGBG  SLATE BBGBG",
+            " 2 CRANE GGGGG  TRACE BGGYG",
+            " 3              CRANE GGGGG",
+            "",
+            "In common: SLATE, CRANE",
+            "alice wins, 2 guesses to 3",
+            "",
+        ];
+        assert_eq!(compare(&alice, &bob).unwrap(), table.join("\n"));
+
+        let other = logged("bob", "moist", &["slate"]);
+        assert_eq!(
+            compare(&alice, &other).unwrap_err(),
+            "Can't compare games of different puzzles, alice had CRANE and bob had MOIST"
+        );
     }
 
-    fn guess(&mut self) {
-        if self.curr.len() == 5 && GUESSES.contains(self.curr.as_str()) {
-            self.guesses.push(std::mem::take(&mut self.curr));
+    #[test]
+    fn autosubmit_waits_for_a_full_row() {
+        let mut wordle = Wordle::with_answer("crane");
+        let mut typed = |c| {
+            let before = wordle.curr.clone();
+            wordle.input(c);
+            filled_row(&wordle, &before)
+        };
+
+        for c in "slat".chars() {
+            assert_eq!(typed(c), None);
         }
+        assert_eq!(typed('e'), Some(true));
+        // a sixth letter is ignored, so the row isn't filled again
+        assert_eq!(typed('s'), None);
+
+        let mut wordle = Wordle::with_answer("crane");
+        wordle.paste("moist");
+        assert_eq!(wordle.guess(), GuessResult::Accepted);
+
+        wordle.paste("slat");
+        let before = wordle.curr.clone();
+        wordle.input('q');
+        assert_eq!(filled_row(&wordle, &before), Some(false));
+
+        // the word that isn't one stays in the row, to be fixed
+        assert_eq!(wordle.curr, "slatq");
+        assert_eq!(wordle.guesses(), ["moist"]);
+        wordle.erase();
+        wordle.input('e');
+        assert_eq!(filled_row(&wordle, "slat"), Some(true));
+        assert_eq!(wordle.curr, "slate");
     }
 
-    fn won(&self) -> Option<bool> {
-        if self.guesses.last() == Some(&self.answer) {
-            Some(true)
-        } else if self.guesses.len() == 6 {
-            Some(false)
-        } else {
+    #[test]
+    fn dupes_hint_counts_distinct_letters() {
+        assert_eq!(
+            dupes_message(&Wordle::with_answer("crane")),
+            "No letter repeats in this word"
+        );
+        assert_eq!(
+            dupes_message(&Wordle::with_answer("eerie")),
+            "This word has 3 distinct letters"
+        );
+    }
+
+    #[test]
+    fn reverse_mode_narrows_down_to_the_word() {
+        let mut candidates = ANSWERS.clone();
+        let mut guess = solver::information_guess(&candidates).unwrap();
+        let mut guesses = 1;
+
+        while guess != "crane" {
+            let clues = score_guess("crane", guess);
+            guess = next_reverse_guess(&mut candidates, guess, &clues).unwrap();
+            guesses += 1;
+        }
+        assert!(guesses <= 6);
+
+        // no answer is all grey against one and all green against another
+        let mut candidates = ANSWERS.clone();
+        next_reverse_guess(&mut candidates, "crane", &[Clue::Grey; 5]);
+        assert_eq!(
+            next_reverse_guess(&mut candidates, "slate", &[Clue::Green; 5]),
             None
+        );
+    }
+
+    #[test]
+    fn strategies_replay_the_same_game() {
+        for strategy in [Strategy::Entropy, Strategy::Frequency, Strategy::Random] {
+            let guesses = strategy.play("crane", 3);
+
+            assert!(guesses.len() <= 6);
+            assert!(guesses.iter().all(|guess| GUESSES.contains(guess)));
+            assert_eq!(guesses, strategy.play("crane", 3));
         }
     }
+
+    #[test]
+    fn interrupted_games_exit_with_130() {
+        assert_eq!(quit_code(false), ExitCode::SUCCESS);
+        assert_eq!(quit_code(true), ExitCode::from(130));
+    }
+
+    #[test]
+    fn export_takes_a_format_and_a_file() {
+        let export = args(&["export", "--format", "csv", "games.csv"]);
+        assert!(matches!(
+            export.command,
+            Some(Command::Export { format: ExportFormat::Csv, file: Some(file) })
+                if file == Path::new("games.csv")
+        ));
+
+        // the flags it took the place of still work
+        let old = args(&["--export-stats", "-", "--export-format", "json"]);
+        assert_eq!(old.export_stats.as_deref(), Some(Path::new("-")));
+        assert!(old.export_format == ExportFormat::Json);
+    }
 }
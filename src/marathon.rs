@@ -0,0 +1,53 @@
+use crate::wordle::Wordle;
+
+/// A run of games played back to back on one budget of guesses, each answer
+/// solved giving some of them back, until a game runs the budget out.
+#[derive(Clone, Copy, Debug)]
+pub struct Marathon {
+    /// Guesses left for the rest of the run, all of them the current game's.
+    pub budget: usize,
+    /// Answers solved so far.
+    pub solved: usize,
+    pub score: usize,
+}
+
+impl Marathon {
+    /// Guesses a run starts with.
+    pub const START: usize = 8;
+    /// Guesses given back for every answer solved.
+    pub const REFILL: usize = 5;
+    /// Most guesses the budget can hold.
+    pub const MAX: usize = 10;
+
+    pub fn new() -> Self {
+        Self {
+            budget: Self::START,
+            solved: 0,
+            score: 0,
+        }
+    }
+
+    /// Gives `wordle` what is left of the budget as its guesses, to play it
+    /// as the next game of the run.
+    pub fn start(&self, wordle: &mut Wordle) {
+        wordle.max_guesses = self.budget;
+    }
+
+    /// Pays for the game just solved in `guesses`, refilling the budget and
+    /// scoring it, from 6 points for a first guess down to 1 for six or more.
+    /// Returns the points scored.
+    pub fn solve(&mut self, guesses: usize) -> usize {
+        let points = 7usize.saturating_sub(guesses).max(1);
+
+        self.budget = (self.budget.saturating_sub(guesses) + Self::REFILL).min(Self::MAX);
+        self.solved += 1;
+        self.score += points;
+        points
+    }
+}
+
+impl Default for Marathon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
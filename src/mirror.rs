@@ -0,0 +1,66 @@
+//! A copy of the board kept up to date in a file or FIFO, for showing the game
+//! somewhere else, such as another pane or an overlay on a stream.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+pub struct Mirror {
+    snapshots: Sender<String>,
+}
+
+impl Mirror {
+    /// Writes the snapshots given to `show` to `path` on a thread of its own,
+    /// as opening a FIFO waits until something reads from it.
+    pub fn new(path: PathBuf) -> Self {
+        let (snapshots, rx) = mpsc::channel::<String>();
+
+        thread::spawn(move || {
+            // a FIFO is kept open so that its reader sees one snapshot after another
+            let mut fifo: Option<File> = None;
+
+            while let Ok(mut snapshot) = rx.recv() {
+                // only the latest is worth writing when writing fell behind
+                while let Ok(newer) = rx.try_recv() {
+                    snapshot = newer;
+                }
+
+                if !is_fifo(&path) {
+                    let _ = fs::write(&path, &snapshot);
+                    continue;
+                }
+
+                if fifo.is_none() {
+                    fifo = File::options().write(true).open(&path).ok();
+                }
+
+                // the reader went away, so the next snapshot waits for a new one
+                if let Some(file) = &mut fifo {
+                    if file.write_all(snapshot.as_bytes()).is_err() {
+                        fifo = None;
+                    }
+                }
+            }
+        });
+
+        Self { snapshots }
+    }
+
+    pub fn show(&self, snapshot: String) {
+        let _ = self.snapshots.send(snapshot);
+    }
+}
+
+#[cfg(unix)]
+fn is_fifo(path: &Path) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+
+    fs::metadata(path).is_ok_and(|meta| meta.file_type().is_fifo())
+}
+
+#[cfg(not(unix))]
+fn is_fifo(_: &Path) -> bool {
+    false
+}
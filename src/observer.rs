@@ -0,0 +1,72 @@
+use crate::wordle::{Clue, GuessResult, Wordle};
+
+/// Something told what happens in a game as it is played, such as a log of
+/// the game or a tool it is passed on to. Every method does nothing unless
+/// implemented, so an observer only picks the events it cares about.
+pub trait GameObserver {
+    /// A guess was accepted and got `clues`, `game` already having it as its
+    /// last guess.
+    fn on_guess(&mut self, game: &Wordle, guess: &str, clues: &[Clue]) {
+        let _ = (game, guess, clues);
+    }
+
+    /// A guess was turned down for `why`, leaving `game` as it was.
+    fn on_invalid(&mut self, game: &Wordle, guess: &str, why: &GuessResult) {
+        let _ = (game, guess, why);
+    }
+
+    /// The game is over. `game.won()` says how it went, `None` meaning that it
+    /// was given up before it was.
+    fn on_game_end(&mut self, game: &Wordle) {
+        let _ = game;
+    }
+
+    /// Tells the observer of `guess` once `game` has been given it, as
+    /// `on_guess` or `on_invalid` depending on `result`, which is what front
+    /// ends call after every guess rather than either of those. An accepted
+    /// guess is passed on as the game took it, with any locked letters.
+    fn guessed(&mut self, game: &Wordle, guess: &str, result: &GuessResult) {
+        if !result.is_accepted() {
+            self.on_invalid(game, guess, result);
+            return;
+        }
+
+        let Some(row) = game.guesses().len().checked_sub(1) else {
+            return;
+        };
+
+        if let Some(clues) = game.clues(row) {
+            self.on_guess(game, &game.guesses()[row], &clues);
+        }
+    }
+}
+
+/// Every observer registered with a game, each told of every event in turn.
+#[derive(Default)]
+pub struct Observers(Vec<Box<dyn GameObserver>>);
+
+impl Observers {
+    pub fn register(&mut self, observer: impl GameObserver + 'static) {
+        self.0.push(Box::new(observer));
+    }
+}
+
+impl GameObserver for Observers {
+    fn on_guess(&mut self, game: &Wordle, guess: &str, clues: &[Clue]) {
+        for observer in &mut self.0 {
+            observer.on_guess(game, guess, clues);
+        }
+    }
+
+    fn on_invalid(&mut self, game: &Wordle, guess: &str, why: &GuessResult) {
+        for observer in &mut self.0 {
+            observer.on_invalid(game, guess, why);
+        }
+    }
+
+    fn on_game_end(&mut self, game: &Wordle) {
+        for observer in &mut self.0 {
+            observer.on_game_end(game);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::wordle::{score_guess, Clue};
+
+/// The clues for a whole guess packed into one number, reading them as a
+/// number in base three with green as 0, so that comparing and bucketing them
+/// is cheap.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Pattern(pub u16);
+
+impl Pattern {
+    /// How many different patterns there are for the longest words.
+    pub const COUNT: usize = 6561;
+
+    pub fn from_clues(clues: &[Clue]) -> Self {
+        Pattern(clues.iter().fold(0, |idx, &clue| 3 * idx + clue as u16))
+    }
+
+    /// The clues of a word `length` letters long that the pattern stands for.
+    pub fn clues(self, length: usize) -> Vec<Clue> {
+        let mut clues = vec![Clue::Grey; length];
+        let mut rest = self.0;
+
+        for clue in clues.iter_mut().rev() {
+            *clue = Clue::ALL[usize::from(rest % 3)];
+            rest /= 3;
+        }
+
+        clues
+    }
+
+    pub fn is_win(self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// Longest word `score` takes.
+const MAX_LENGTH: usize = 8;
+
+/// Scores a guess of lowercase ASCII letters against an answer as long, of
+/// up to eight letters, like `score_guess`, without allocating.
+pub fn score(guess: &[u8], answer: &[u8]) -> Pattern {
+    let length = guess.len().min(MAX_LENGTH);
+    let mut clues = [2u16; MAX_LENGTH];
+    let mut unmatched = [0u8; 26];
+
+    for idx in 0..length {
+        if guess[idx] == answer[idx] {
+            clues[idx] = 0;
+        } else {
+            unmatched[usize::from(answer[idx].wrapping_sub(b'a')) % 26] += 1;
+        }
+    }
+
+    for idx in 0..length {
+        let count = &mut unmatched[usize::from(guess[idx].wrapping_sub(b'a')) % 26];
+
+        if clues[idx] != 0 && *count > 0 {
+            clues[idx] = 1;
+            *count -= 1;
+        }
+    }
+
+    Pattern(clues[..length].iter().fold(0, |idx, &clue| 3 * idx + clue))
+}
+
+/// The word as bytes for `score`, if it is lowercase ASCII letters and no
+/// longer than it takes.
+pub(crate) fn ascii_word(word: &str) -> Option<&[u8]> {
+    Some(word.as_bytes())
+        .filter(|bytes| bytes.len() <= MAX_LENGTH && bytes.iter().all(u8::is_ascii_lowercase))
+}
+
+/// Scores `guess` against `answer`, quickly when both are plain ASCII and
+/// falling back to `score_guess` for words from other alphabets.
+pub fn pattern(answer: &str, guess: &str) -> Pattern {
+    match (ascii_word(guess), ascii_word(answer)) {
+        (Some(guess), Some(answer)) if guess.len() == answer.len() => score(guess, answer),
+        _ => Pattern::from_clues(&score_guess(answer, guess)),
+    }
+}
+
+/// Every pattern between the words of a list, scored up front so that the
+/// solver can look them up by position instead of scoring them over and over.
+pub struct PatternTable {
+    words: Vec<&'static str>,
+    index: HashMap<&'static str, usize>,
+    patterns: Vec<Pattern>,
+}
+
+impl PatternTable {
+    pub fn new(words: &[&'static str]) -> Self {
+        let patterns = words
+            .iter()
+            .flat_map(|guess| words.iter().map(|answer| pattern(answer, guess)))
+            .collect();
+
+        Self {
+            words: words.to_vec(),
+            index: words
+                .iter()
+                .enumerate()
+                .map(|(idx, &word)| (word, idx))
+                .collect(),
+            patterns,
+        }
+    }
+
+    pub fn words(&self) -> &[&'static str] {
+        &self.words
+    }
+
+    /// Position of `word` in the list, if it is in it.
+    pub fn index(&self, word: &str) -> Option<usize> {
+        self.index.get(word).copied()
+    }
+
+    /// The pattern the word at `guess` gets against the word at `answer`.
+    pub fn get(&self, guess: usize, answer: usize) -> Pattern {
+        self.patterns[guess * self.words.len() + answer]
+    }
+}
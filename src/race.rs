@@ -0,0 +1,127 @@
+//! Racing a friend over the network to the same answer, each side sending the
+//! other the clues for its guesses, but never the letters.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+
+use wordle::{Clue, WORD_LENGTHS};
+
+/// What goes over the wire, one JSON object per line.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Message {
+    /// Sent by the host once the friend joins, picking the answer for both.
+    Start {
+        seed: u64,
+    },
+    /// Clues such as `GYBBB` for a guess.
+    Guess {
+        clues: String,
+    },
+    Done {
+        won: bool,
+    },
+}
+
+/// News of the other player.
+pub enum RaceEvent {
+    Guess(Vec<Clue>),
+    Done {
+        won: bool,
+    },
+    /// The connection dropped or the other side sent something unreadable.
+    Left,
+}
+
+pub struct Race {
+    stream: TcpStream,
+    events: Receiver<RaceEvent>,
+}
+
+impl Race {
+    /// Waits on `port` for a friend to join, then starts them on `seed`.
+    pub fn host(port: u16, seed: u64) -> io::Result<Self> {
+        let listener = TcpListener::bind(("0.0.0.0", port))?;
+        let (stream, _) = listener.accept()?;
+        let mut race = Self::start(stream)?;
+        race.send(&Message::Start { seed })?;
+
+        Ok(race)
+    }
+
+    /// Joins the friend hosting at `addr`, returning the seed they picked.
+    pub fn join(addr: &str) -> io::Result<(Self, u64)> {
+        let stream = TcpStream::connect(addr)?;
+        let mut line = String::new();
+        BufReader::new(stream.try_clone()?).read_line(&mut line)?;
+
+        let Ok(Message::Start { seed }) = serde_json::from_str(&line) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{addr} isn't hosting a race"),
+            ));
+        };
+
+        Ok((Self::start(stream)?, seed))
+    }
+
+    /// Reads what the other side sends on a thread of its own, so that it can
+    /// be picked up between keypresses.
+    fn start(stream: TcpStream) -> io::Result<Self> {
+        let reader = BufReader::new(stream.try_clone()?);
+        let (tx, events) = mpsc::channel();
+
+        thread::spawn(move || {
+            for line in reader.lines() {
+                let event = match line.ok().and_then(|line| serde_json::from_str(&line).ok()) {
+                    Some(Message::Guess { clues }) => match parse_clues(&clues) {
+                        Some(clues) => RaceEvent::Guess(clues),
+                        None => break,
+                    },
+                    Some(Message::Done { won }) => RaceEvent::Done { won },
+                    Some(Message::Start { .. }) | None => break,
+                };
+
+                if tx.send(event).is_err() {
+                    return;
+                }
+            }
+
+            let _ = tx.send(RaceEvent::Left);
+        });
+
+        Ok(Self { stream, events })
+    }
+
+    fn send(&mut self, message: &Message) -> io::Result<()> {
+        let mut line = serde_json::to_string(message)?;
+        line.push('\n');
+        self.stream.write_all(line.as_bytes())
+    }
+
+    pub fn send_guess(&mut self, clues: &[Clue]) -> io::Result<()> {
+        let clues = clues.iter().map(|clue| clue.letter()).collect();
+        self.send(&Message::Guess { clues })
+    }
+
+    pub fn send_done(&mut self, won: bool) -> io::Result<()> {
+        self.send(&Message::Done { won })
+    }
+
+    /// Everything the other side sent since last asked.
+    pub fn events(&self) -> impl Iterator<Item = RaceEvent> + '_ {
+        self.events.try_iter()
+    }
+}
+
+fn parse_clues(clues: &str) -> Option<Vec<Clue>> {
+    let clues: Vec<Clue> = clues
+        .chars()
+        .map(Clue::from_letter)
+        .collect::<Option<_>>()?;
+    WORD_LENGTHS.contains(&clues.len()).then_some(clues)
+}
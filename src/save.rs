@@ -0,0 +1,169 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use wordle::{letter_count, Wordle, GUESSES};
+
+/// A game left unfinished, kept until it is picked up again with `--resume`.
+#[derive(Serialize, Deserialize)]
+pub struct SavedGame {
+    pub answer: String,
+    /// The guess being typed when the game was left.
+    pub curr: String,
+    pub guesses: Vec<String>,
+    pub hints: usize,
+    pub seed: Option<u64>,
+    pub day: Option<i64>,
+    /// Missing from saves made before hard mode was kept with them.
+    #[serde(default)]
+    pub hard: bool,
+    #[serde(default)]
+    pub ultra_hard: bool,
+}
+
+impl SavedGame {
+    /// Location of the save file, usually `~/.local/share/wordle/save.json`.
+    pub fn path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("wordle").join("save.json"))
+    }
+
+    pub fn from_game(wordle: &Wordle) -> Self {
+        Self {
+            answer: wordle.answer.clone(),
+            curr: wordle.curr.clone(),
+            guesses: wordle.guesses().to_vec(),
+            hints: wordle.hints,
+            seed: wordle.seed,
+            day: wordle.day,
+            hard: wordle.hard,
+            ultra_hard: wordle.ultra_hard,
+        }
+    }
+
+    /// Puts the saved state back into `wordle`, which should be a game started
+    /// with the same settings, failing if the save couldn't be from a game that
+    /// was still going.
+    pub fn restore(self, wordle: &mut Wordle) -> Result<(), String> {
+        let length = wordle.length();
+        if letter_count(&self.answer) != length || !GUESSES.contains(&self.answer) {
+            return Err(format!("`{}` isn't a {length}-letter word", self.answer));
+        }
+
+        if letter_count(&self.curr) > length {
+            return Err(format!("`{}` is too long to be a guess", self.curr));
+        }
+
+        wordle.answer = self.answer;
+        wordle.curr = self.curr;
+        wordle.set_guesses(self.guesses);
+        wordle.hints = self.hints;
+        wordle.seed = self.seed;
+        wordle.day = self.day;
+        wordle.hard = self.hard;
+        wordle.ultra_hard = self.ultra_hard;
+
+        match wordle.won() {
+            Some(_) => Err(String::from("the game was already over")),
+            None => Ok(()),
+        }
+    }
+
+    /// Reads the saved game and removes the save file, so that it is only ever
+    /// resumed once, even when it turns out to be unreadable.
+    pub fn take() -> io::Result<Option<Self>> {
+        let Some(path) = Self::path() else {
+            return Ok(None);
+        };
+
+        let json = match fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        fs::remove_file(&path)?;
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(io::Error::from)
+    }
+
+    pub fn exists() -> bool {
+        Self::path().is_some_and(|path| path.exists())
+    }
+
+    /// Removes the save file, if there is one.
+    pub fn discard() -> io::Result<()> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+
+        match fs::remove_file(path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = Self::path() else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no data directory to save the game in",
+            ));
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_resume_where_they_were_left() {
+        let mut wordle = Wordle::with_answer("crane");
+        wordle.hard = true;
+        wordle.curr = String::from("slate");
+        wordle.guess();
+        wordle.curr = String::from("mo");
+
+        let json = serde_json::to_string(&SavedGame::from_game(&wordle)).unwrap();
+        let saved: SavedGame = serde_json::from_str(&json).unwrap();
+
+        let mut resumed = Wordle::with_answer("moist");
+        saved.restore(&mut resumed).unwrap();
+        assert_eq!(resumed.answer, "crane");
+        assert_eq!(resumed.guesses(), ["slate"]);
+        assert_eq!(resumed.curr, "mo");
+        assert!(resumed.hard);
+    }
+
+    #[test]
+    fn finished_or_broken_saves_are_turned_down() {
+        let mut won = Wordle::with_answer("crane");
+        won.curr = String::from("crane");
+        won.guess();
+        let restore = |saved: SavedGame| saved.restore(&mut Wordle::with_answer("moist"));
+
+        assert_eq!(
+            restore(SavedGame::from_game(&won)),
+            Err(String::from("the game was already over"))
+        );
+
+        // saves made before hard mode was kept have no `hard`
+        let old: SavedGame = serde_json::from_str(
+            r#"{"answer":"qqqqq","curr":"","guesses":[],"hints":0,"seed":null,"day":null}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            restore(old),
+            Err(String::from("`qqqqq` isn't a 5-letter word"))
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::Display;
+use std::io::{self, Write};
+
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::{ContentStyle, PrintStyledContent, StyledContent};
+use crossterm::terminal::{Clear, ClearType};
+use unicode_width::UnicodeWidthChar;
+
+/// Stands in for the right half of a wide glyph, which the terminal fills in itself.
+const CONTINUATION: char = '\0';
+
+#[derive(Clone, Copy, PartialEq)]
+struct Cell {
+    symbol: char,
+    style: ContentStyle,
+}
+
+impl Cell {
+    fn blank() -> Self {
+        Self {
+            symbol: ' ',
+            style: ContentStyle::new(),
+        }
+    }
+}
+
+/// Everything on screen for one frame, drawn off screen before any of it is written out.
+pub struct Frame {
+    width: u16,
+    height: u16,
+    cells: Vec<Cell>,
+}
+
+impl Frame {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::blank(); width as usize * height as usize],
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Writes `content` from `(x, y)` onwards, dropping whatever runs off the frame.
+    pub fn print<D: Display>(&mut self, x: u16, y: u16, content: StyledContent<D>) {
+        if y >= self.height {
+            return;
+        }
+
+        let style = *content.style();
+        let row = y as usize * self.width as usize;
+        let mut x = x as usize;
+
+        for symbol in content.content().to_string().chars() {
+            let width = symbol.width().unwrap_or(0);
+
+            if width == 0 {
+                continue;
+            }
+
+            if x + width > self.width as usize {
+                break;
+            }
+
+            self.cells[row + x] = Cell { symbol, style };
+
+            if width == 2 {
+                self.cells[row + x + 1] = Cell {
+                    symbol: CONTINUATION,
+                    style,
+                };
+            }
+
+            x += width;
+        }
+    }
+}
+
+/// Writes frames to the terminal, sending only the cells that changed since the last one.
+#[derive(Default)]
+pub struct Screen {
+    last: Option<Frame>,
+}
+
+impl Screen {
+    /// Forgets what is on screen, for when something else has drawn over it.
+    pub fn invalidate(&mut self) {
+        self.last = None;
+    }
+
+    pub fn draw(&mut self, frame: Frame) -> io::Result<()> {
+        let mut stdout = io::stdout();
+
+        // a resize leaves the old frame meaningless, so start over from a blank screen
+        let last = self
+            .last
+            .take()
+            .filter(|last| (last.width, last.height) == (frame.width, frame.height));
+
+        if last.is_none() {
+            queue!(stdout, Clear(ClearType::All))?;
+        }
+
+        let mut cursor = None;
+
+        for (idx, cell) in frame.cells.iter().enumerate() {
+            let before = last.as_ref().map_or(Cell::blank(), |last| last.cells[idx]);
+
+            if *cell == before || cell.symbol == CONTINUATION {
+                continue;
+            }
+
+            let x = (idx % frame.width as usize) as u16;
+            let y = (idx / frame.width as usize) as u16;
+
+            if cursor != Some((x, y)) {
+                queue!(stdout, MoveTo(x, y))?;
+            }
+
+            queue!(
+                stdout,
+                PrintStyledContent(StyledContent::new(cell.style, cell.symbol))
+            )?;
+
+            cursor = Some((x + cell.symbol.width().unwrap_or(0) as u16, y));
+        }
+
+        stdout.flush()?;
+        self.last = Some(frame);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,491 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use lazy_static::lazy_static;
+
+use crate::pattern::{pattern, Pattern, PatternTable};
+use crate::wordle::ANSWERS;
+
+lazy_static! {
+    /// The guess expected to tell the most about an answer before any clues,
+    /// worked out once as it is the same every game.
+    static ref OPENER: &'static str = best_guesses(&ANSWERS, &ANSWERS, 1)[0].0;
+
+    /// Every answer scored against every other, for picking guesses among the
+    /// answers without scoring the same pairs game after game.
+    static ref ANSWER_PATTERNS: PatternTable = PatternTable::new(&ANSWERS);
+
+    /// How many other answers differ from each answer by a single letter,
+    /// counted once for all of them so that rating every answer stays quick.
+    static ref NEIGHBOR_COUNTS: HashMap<&'static str, usize> = {
+        // answers sharing every letter but the one in a given spot
+        let mut patterns: HashMap<(usize, String), usize> = HashMap::new();
+        let blanked = |word: &str, idx: usize| -> String {
+            word.chars()
+                .enumerate()
+                .map(|(at, c)| if at == idx { '_' } else { c })
+                .collect()
+        };
+
+        for answer in ANSWERS.iter() {
+            for idx in 0..answer.chars().count() {
+                *patterns.entry((idx, blanked(answer, idx))).or_default() += 1;
+            }
+        }
+
+        ANSWERS
+            .iter()
+            .map(|&answer| {
+                let count = (0..answer.chars().count())
+                    .map(|idx| patterns[&(idx, blanked(answer, idx))] - 1)
+                    .sum();
+                (answer, count)
+            })
+            .collect()
+    };
+}
+
+/// Picks the candidate expected to leave the fewest candidates behind, that is
+/// the one whose feedback splits the candidates into the smallest groups.
+///
+/// `candidates` must not be empty.
+pub fn suggest(candidates: &[&'static str]) -> &'static str {
+    candidates
+        .iter()
+        .copied()
+        .min_by_key(|guess| split_score(guess, candidates))
+        .unwrap()
+}
+
+/// Sum of the squared sizes of the groups `guess` splits the candidates into by
+/// their feedback, which is how many candidates it is expected to leave times
+/// the number of candidates.
+fn split_score(guess: &str, candidates: &[&str]) -> usize {
+    let mut buckets = [0; Pattern::COUNT];
+
+    for answer in candidates {
+        buckets[usize::from(pattern(answer, guess).0)] += 1;
+    }
+
+    buckets.iter().map(|size| size * size).sum()
+}
+
+/// How many bits of information `guess` is expected to give about which of
+/// the candidates is the answer, from how evenly its feedback splits them.
+pub fn expected_information(guess: &str, candidates: &[&str]) -> f64 {
+    let mut buckets = [0; Pattern::COUNT];
+
+    for answer in candidates {
+        buckets[usize::from(pattern(answer, guess).0)] += 1;
+    }
+
+    information(&buckets, candidates.len())
+}
+
+/// Bits of information in feedback splitting `total` candidates into groups of
+/// the sizes in `buckets`.
+fn information(buckets: &[usize; Pattern::COUNT], total: usize) -> f64 {
+    let total = total as f64;
+
+    buckets
+        .iter()
+        .filter(|&&size| size > 0)
+        .map(|&size| {
+            let p = size as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// The `count` guesses expected to give the most information about the
+/// candidates, best first, along with how many bits each is expected to give.
+/// Among guesses that tie, ones that could be the answer come first.
+pub fn best_guesses(
+    guesses: &[&'static str],
+    candidates: &[&str],
+    count: usize,
+) -> Vec<(&'static str, f64)> {
+    let possible: HashSet<&str> = candidates.iter().copied().collect();
+
+    let mut ranked: Vec<(&'static str, f64, bool)> = guesses
+        .iter()
+        .map(|&guess| {
+            let information = expected_information(guess, candidates);
+            (guess, information, possible.contains(guess))
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.2.cmp(&a.2)).then(a.0.cmp(b.0)));
+
+    ranked
+        .into_iter()
+        .take(count)
+        .map(|(guess, information, _)| (guess, information))
+        .collect()
+}
+
+/// Scores every guess as an opener by the average number of `answers` it leaves
+/// possible, best first. The work is spread over all cores, and `progress` is
+/// called with the number of guesses scored so far as they finish.
+pub fn rank_openers(
+    guesses: &[&'static str],
+    answers: &[&str],
+    progress: impl Fn(usize) + Sync,
+) -> Vec<(&'static str, f64)> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk = guesses.len().div_ceil(threads).max(1);
+    let scored = AtomicUsize::new(0);
+
+    let score = |chunk: &[&'static str]| {
+        chunk
+            .iter()
+            .map(|&guess| {
+                let score = split_score(guess, answers) as f64 / answers.len() as f64;
+                progress(scored.fetch_add(1, Ordering::Relaxed) + 1);
+                (guess, score)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // with only the one thread, as in a browser, where none can be spawned
+    let mut ranked: Vec<(&'static str, f64)> = if threads == 1 {
+        score(guesses)
+    } else {
+        thread::scope(|scope| {
+            let workers: Vec<_> = guesses
+                .chunks(chunk)
+                .map(|chunk| scope.spawn(|| score(chunk)))
+                .collect();
+
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect()
+        })
+    };
+
+    ranked.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(b.0)));
+    ranked
+}
+
+/// Plays against `answer` starting from `opener`, returning every guess made
+/// until it was found. Gives up after `limit` guesses.
+pub fn solve(
+    answer: &str,
+    candidates: &[&'static str],
+    opener: &'static str,
+    limit: usize,
+) -> Vec<&'static str> {
+    let mut candidates = candidates.to_vec();
+    let mut guesses = vec![opener];
+
+    while guesses.len() < limit && *guesses.last().unwrap() != answer {
+        let guess = guesses.last().unwrap();
+        let clues = pattern(answer, guess);
+        candidates.retain(|candidate| pattern(candidate, guess) == clues);
+
+        if candidates.is_empty() {
+            break;
+        }
+
+        guesses.push(suggest(&candidates));
+    }
+
+    guesses
+}
+
+/// How one guess of a game went, next to the best guess there was.
+pub struct GuessAnalysis {
+    pub guess: String,
+    /// Answers possible before and after the guess.
+    pub before: usize,
+    pub after: usize,
+    /// Bits the guess was expected to give, and the best guess along with the bits it would have.
+    pub expected: f64,
+    pub best: &'static str,
+    pub best_expected: f64,
+}
+
+impl GuessAnalysis {
+    /// Bits the guess actually gave, by how far it cut down the answers.
+    pub fn actual(&self) -> f64 {
+        (self.before as f64 / self.after.max(1) as f64).log2()
+    }
+}
+
+/// Goes over each guess made against `answer`, leaving out burned rows.
+pub fn analyze(answer: &str, guesses: &[String]) -> Vec<GuessAnalysis> {
+    let mut candidates = ANSWERS.clone();
+    let mut analysis = Vec::new();
+
+    for guess in guesses.iter().filter(|guess| !guess.is_empty()) {
+        let (best, best_expected) = if analysis.is_empty() {
+            (*OPENER, expected_information(&OPENER, &candidates))
+        } else {
+            best_guesses(&ANSWERS, &candidates, 1)[0]
+        };
+
+        let before = candidates.len();
+        let expected = expected_information(guess, &candidates);
+        let clues = pattern(answer, guess);
+        candidates.retain(|candidate| pattern(candidate, guess) == clues);
+
+        analysis.push(GuessAnalysis {
+            guess: guess.clone(),
+            before,
+            after: candidates.len(),
+            expected,
+            best,
+            best_expected,
+        });
+    }
+
+    analysis
+}
+
+/// How well the guesses were chosen, from 0 to 100: the bits they were
+/// expected to give as a share of what the best guesses would have.
+pub fn skill(analysis: &[GuessAnalysis]) -> usize {
+    let expected: f64 = analysis.iter().map(|step| step.expected).sum();
+    let best: f64 = analysis.iter().map(|step| step.best_expected).sum();
+
+    if best > 0.0 {
+        (100.0 * expected / best).round().min(100.0) as usize
+    } else {
+        100
+    }
+}
+
+/// How kind the clues were, from 0 to 100, with 50 for clues that gave exactly
+/// the bits expected and every bit more or less on average moving it by 25.
+pub fn luck(analysis: &[GuessAnalysis]) -> usize {
+    let surplus: f64 = analysis
+        .iter()
+        .map(|step| step.actual() - step.expected)
+        .sum::<f64>()
+        / analysis.len().max(1) as f64;
+
+    (50.0 + 25.0 * surplus).round().clamp(0.0, 100.0) as usize
+}
+
+/// Plays against `answer` always making the guess expected to give the most
+/// information about the answers left, returning every guess made until it was
+/// found. Gives up after `limit` guesses.
+pub fn solve_by_information(answer: &str, limit: usize) -> Vec<&'static str> {
+    play(answer, limit, information_guess)
+}
+
+/// Plays against `answer` making whichever guess `pick` chooses given the
+/// answers still possible, returning every guess made until it was found.
+/// Gives up after `limit` guesses, or when `pick` has none to make.
+pub fn play(
+    answer: &str,
+    limit: usize,
+    mut pick: impl FnMut(&[&'static str]) -> Option<&'static str>,
+) -> Vec<&'static str> {
+    let mut candidates = ANSWERS.clone();
+    let mut guesses = Vec::new();
+
+    while guesses.len() < limit {
+        let Some(guess) = pick(&candidates) else {
+            break;
+        };
+
+        guesses.push(guess);
+
+        if guess == answer {
+            break;
+        }
+
+        let clues = pattern(answer, guess);
+        candidates.retain(|candidate| pattern(candidate, guess) == clues);
+    }
+
+    guesses
+}
+
+/// The guess expected to give the most information about which of `candidates`
+/// is the answer, or `None` when there are none left.
+pub fn information_guess(candidates: &[&str]) -> Option<&'static str> {
+    match candidates.len() {
+        0 => None,
+        all if all == ANSWERS.len() => Some(*OPENER),
+        _ => match candidates
+            .iter()
+            .map(|candidate| ANSWER_PATTERNS.index(candidate))
+            .collect::<Option<Vec<usize>>>()
+        {
+            Some(candidates) => Some(best_in_table(&ANSWER_PATTERNS, &candidates)),
+            None => best_guesses(&ANSWERS, candidates, 1)
+                .first()
+                .map(|&(guess, _)| guess),
+        },
+    }
+}
+
+/// The word of `table` that `best_guesses` would rank first among its words,
+/// given the positions of the candidates in it.
+fn best_in_table(table: &PatternTable, candidates: &[usize]) -> &'static str {
+    let words = table.words();
+    let possible: HashSet<usize> = candidates.iter().copied().collect();
+
+    let (best, ..) = (0..words.len())
+        .map(|guess| {
+            let mut buckets = [0; Pattern::COUNT];
+
+            for &answer in candidates {
+                buckets[usize::from(table.get(guess, answer).0)] += 1;
+            }
+
+            let information = information(&buckets, candidates.len());
+            (guess, information, possible.contains(&guess))
+        })
+        .max_by(|a, b| {
+            a.1.total_cmp(&b.1)
+                .then(a.2.cmp(&b.2))
+                .then(words[b.0].cmp(words[a.0]))
+        })
+        .unwrap();
+
+    words[best]
+}
+
+/// How many of the candidates each letter is in, counting it once per word.
+pub fn letter_counts(candidates: &[&str]) -> HashMap<char, usize> {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+
+    for candidate in candidates {
+        let letters: HashSet<char> = candidate.chars().collect();
+
+        for c in letters {
+            *counts.entry(c).or_default() += 1;
+        }
+    }
+
+    counts
+}
+
+/// The candidate whose letters are the most common among the candidates,
+/// counting each letter once, or `None` when there are none left.
+pub fn frequency_guess(candidates: &[&'static str]) -> Option<&'static str> {
+    let counts = letter_counts(candidates);
+
+    candidates.iter().copied().max_by_key(|candidate| {
+        let letters: HashSet<char> = candidate.chars().collect();
+        let score: usize = letters.iter().map(|c| counts[c]).sum();
+        // the first of those that tie
+        (score, std::cmp::Reverse(*candidate))
+    })
+}
+
+/// The words in `answers` that differ from `word` in exactly one position.
+pub fn one_letter_neighbors<'a>(word: &str, answers: &[&'a str]) -> Vec<&'a str> {
+    answers
+        .iter()
+        .copied()
+        .filter(|other| {
+            let differing = word.chars().zip(other.chars()).filter(|(a, b)| a != b);
+            differing.count() == 1
+        })
+        .collect()
+}
+
+/// Rates how hard `answer` is to find, from 1 to 5.
+///
+/// Answers get harder the more other answers differ from them by a single
+/// letter (think `_ATCH`), since each of those can cost a guess once the rest
+/// is known: none rates 1, then 1-2, 3-4, 5-7 and 8 or more rate 2 to 5.
+/// A repeated letter adds one more, as players rarely try them.
+pub fn difficulty_rating(answer: &str) -> u8 {
+    let neighbors = NEIGHBOR_COUNTS
+        .get(answer)
+        .copied()
+        .unwrap_or_else(|| one_letter_neighbors(answer, &ANSWERS).len());
+
+    let rating = match neighbors {
+        0 => 1,
+        1..=2 => 2,
+        3..=4 => 3,
+        5..=7 => 4,
+        _ => 5,
+    };
+
+    let mut letters: Vec<char> = answer.chars().collect();
+    letters.sort_unstable();
+    letters.dedup();
+
+    if letters.len() < answer.len() {
+        (rating + 1).min(5)
+    } else {
+        rating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: [&str; 3] = ["crane", "crate", "crape"];
+
+    #[test]
+    fn split_score_favors_even_splits() {
+        // INPUT tells all three apart, CRANE can't tell CRATE from CRAPE
+        assert_eq!(split_score("input", &WORDS), 3);
+        assert_eq!(split_score("crane", &WORDS), 5);
+        assert_eq!(split_score("moist", &WORDS), 5);
+    }
+
+    #[test]
+    fn rank_openers_puts_the_best_first() {
+        let scored = AtomicUsize::new(0);
+        let ranked = rank_openers(&["moist", "crane", "input"], &WORDS, |done| {
+            scored.fetch_max(done, Ordering::Relaxed);
+        });
+
+        let order: Vec<&str> = ranked.iter().map(|&(guess, _)| guess).collect();
+        assert_eq!(order, ["input", "crane", "moist"]);
+        assert_eq!(ranked[0].1, 1.0);
+        assert_eq!(scored.into_inner(), 3);
+    }
+
+    #[test]
+    fn difficulty_rating_counts_neighbors_and_repeats() {
+        assert_eq!(difficulty_rating("actor"), 1);
+        assert_eq!(difficulty_rating("nymph"), 2);
+        assert_eq!(difficulty_rating("crane"), 4);
+        assert_eq!(difficulty_rating("batch"), 5);
+        // no neighbors, but the double F makes it harder
+        assert_eq!(difficulty_rating("offer"), 2);
+    }
+
+    #[test]
+    fn one_letter_neighbors_differ_in_a_single_place() {
+        let answers = ["catch", "match", "watch", "chant", "batch", "botch"];
+        assert_eq!(
+            one_letter_neighbors("batch", &answers),
+            ["catch", "match", "watch", "botch"]
+        );
+        assert!(one_letter_neighbors("nymph", &answers).is_empty());
+    }
+
+    #[test]
+    fn frequency_guess_picks_the_most_common_letters() {
+        let counts = letter_counts(&["geese", "crane"]);
+        assert_eq!((counts[&'e'], counts[&'s']), (2, 1));
+
+        // all three share C, R, A and E, while T is in two of them and N in one
+        assert_eq!(frequency_guess(&["crane", "crate", "trace"]), Some("crate"));
+        assert_eq!(frequency_guess(&[]), None);
+    }
+
+    #[test]
+    fn play_stops_at_the_answer_or_the_limit() {
+        let guesses = play("crane", 6, information_guess);
+        assert_eq!(guesses.last(), Some(&"crane"));
+        assert!(guesses.len() <= 6);
+
+        assert_eq!(play("crane", 1, |_| Some("moist")), ["moist"]);
+        assert!(play("crane", 6, |_| None).is_empty());
+    }
+}
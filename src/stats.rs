@@ -0,0 +1,720 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use wordle::{score_guess, Clue};
+
+/// Every game played, as kept in the stats file. Fields added since the first
+/// version default when missing, so that older files keep loading.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Stats {
+    pub games: Vec<GameRecord>,
+    /// Answers lost to or given up on, oldest first, until `--review` sees them solved.
+    #[serde(default)]
+    pub missed: Vec<String>,
+    #[serde(default)]
+    pub achievements: BTreeSet<Achievement>,
+    /// Highest score of any `--marathon` run.
+    #[serde(default)]
+    pub marathon_best: usize,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub date: NaiveDate,
+    pub answer: String,
+    pub guesses: usize,
+    pub outcome: Outcome,
+    #[serde(default)]
+    pub hints: usize,
+    /// Every guess in order, with burned rows left empty. Missing from games
+    /// recorded before guesses were kept.
+    #[serde(default)]
+    pub words: Vec<String>,
+    /// Number of the daily puzzle, for games that were one.
+    #[serde(default)]
+    pub day: Option<i64>,
+    /// Time spent playing, in seconds, for games recorded since it was kept.
+    #[serde(default)]
+    pub seconds: Option<u64>,
+    #[serde(default)]
+    pub hard: bool,
+    /// The kind of game, such as `Classic` or `Jotto`, for games recorded since
+    /// it was kept.
+    #[serde(default)]
+    pub mode: Option<String>,
+    /// Whether guesses were taken back, which leaves the game out of the win
+    /// rate, streaks, distribution and achievements.
+    #[serde(default)]
+    pub unranked: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Won,
+    Lost,
+    Forfeit,
+}
+
+/// The kind of game a record is, which the stats can be narrowed down to so
+/// that, say, jotto games don't count against a classic streak.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GameKind {
+    /// `None` for games recorded before the mode was kept.
+    pub mode: Option<String>,
+    pub letters: usize,
+    pub hard: bool,
+}
+
+impl GameKind {
+    pub fn of(game: &GameRecord) -> Self {
+        Self {
+            mode: game.mode.clone(),
+            letters: game.answer.chars().count(),
+            hard: game.hard,
+        }
+    }
+
+    /// Such as `Jotto · 5 letters · hard`.
+    pub fn label(&self) -> String {
+        let mode = self.mode.as_deref().unwrap_or("Earlier games");
+        let hard = if self.hard { " · hard" } else { "" };
+        format!("{mode} · {} letters{hard}", self.letters)
+    }
+}
+
+impl Outcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Won => "won",
+            Outcome::Lost => "lost",
+            Outcome::Forfeit => "forfeit",
+        }
+    }
+}
+
+/// Milestones unlocked once and kept for good.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Achievement {
+    FirstWin,
+    WinInTwo,
+    TenStreak,
+    NoGreys,
+    QuickDaily,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 5] = [
+        Achievement::FirstWin,
+        Achievement::WinInTwo,
+        Achievement::TenStreak,
+        Achievement::NoGreys,
+        Achievement::QuickDaily,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Achievement::FirstWin => "First win",
+            Achievement::WinInTwo => "Sharpshooter",
+            Achievement::TenStreak => "On a roll",
+            Achievement::NoGreys => "No wasted letters",
+            Achievement::QuickDaily => "Early bird",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Achievement::FirstWin => "win a game",
+            Achievement::WinInTwo => "win in two guesses or fewer",
+            Achievement::TenStreak => "win 10 games in a row",
+            Achievement::NoGreys => "win without a single grey letter",
+            Achievement::QuickDaily => "solve a daily puzzle in under a minute",
+        }
+    }
+
+    /// Whether any game in `stats` has earned it.
+    fn earned(self, stats: &Stats) -> bool {
+        let mut wins = stats.ranked().filter(|game| game.outcome == Outcome::Won);
+
+        match self {
+            Achievement::FirstWin => wins.next().is_some(),
+            Achievement::WinInTwo => wins.any(|game| game.guesses <= 2),
+            Achievement::TenStreak => stats.max_streak() >= 10,
+            // games from before guesses were kept can't tell
+            Achievement::NoGreys => wins.any(|game| {
+                !game.words.is_empty()
+                    && game.words.iter().all(|word| {
+                        !word.is_empty() && !score_guess(&game.answer, word).contains(&Clue::Grey)
+                    })
+            }),
+            Achievement::QuickDaily => wins
+                .any(|game| game.day.is_some() && game.seconds.is_some_and(|seconds| seconds < 60)),
+        }
+    }
+}
+
+impl Stats {
+    /// Location of the stats file, usually `~/.local/share/wordle/stats.json`.
+    pub fn path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("wordle").join("stats.json"))
+    }
+
+    /// Loads the stats file, treating a missing file as no games played.
+    pub fn load() -> io::Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(io::Error::from),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    /// Adds the game, queueing its answer for review if it got away, or taking it
+    /// off the queue once it is solved. Gives the achievements it unlocked.
+    pub fn record_game(&mut self, record: GameRecord) -> Vec<Achievement> {
+        match record.outcome {
+            Outcome::Won => self.missed.retain(|word| *word != record.answer),
+            // giving up before a single guess isn't really missing the word
+            Outcome::Forfeit if record.guesses == 0 => {}
+            Outcome::Lost | Outcome::Forfeit => {
+                if !self.missed.contains(&record.answer) {
+                    self.missed.push(record.answer.clone());
+                }
+            }
+        }
+
+        self.games.push(record);
+
+        let unlocked: Vec<Achievement> = Achievement::ALL
+            .into_iter()
+            .filter(|achievement| !self.achievements.contains(achievement))
+            .filter(|achievement| achievement.earned(self))
+            .collect();
+
+        self.achievements.extend(&unlocked);
+        unlocked
+    }
+
+    /// Every kind of game played, which `only` can narrow the stats down to.
+    pub fn kinds(&self) -> Vec<GameKind> {
+        let kinds: BTreeSet<GameKind> = self.games.iter().map(GameKind::of).collect();
+        kinds.into_iter().collect()
+    }
+
+    /// The games of one kind alone, to work out their numbers apart from the
+    /// rest. Missed words, achievements and the marathon best are left out, as
+    /// they belong to every kind at once.
+    pub fn only(&self, kind: &GameKind) -> Stats {
+        Stats {
+            games: self
+                .games
+                .iter()
+                .filter(|game| GameKind::of(game) == *kind)
+                .cloned()
+                .collect(),
+            ..Stats::default()
+        }
+    }
+
+    /// Every game played without taking guesses back.
+    fn ranked(&self) -> impl Iterator<Item = &GameRecord> {
+        self.games.iter().filter(|game| !game.unranked)
+    }
+
+    /// Keeps `score` as the marathon high score if it beats the one before,
+    /// returning whether it did.
+    pub fn record_marathon(&mut self, score: usize) -> bool {
+        let best = score > self.marathon_best;
+        self.marathon_best = self.marathon_best.max(score);
+        best
+    }
+
+    pub fn wins(&self) -> usize {
+        self.ranked()
+            .filter(|game| game.outcome == Outcome::Won)
+            .count()
+    }
+
+    pub fn win_percentage(&self) -> usize {
+        (100 * self.wins())
+            .checked_div(self.ranked().count())
+            .unwrap_or(0)
+    }
+
+    /// Lengths of every run of consecutive wins, oldest first, which unranked
+    /// games neither add to nor break.
+    fn streaks(&self) -> impl Iterator<Item = usize> + '_ {
+        self.games
+            .split(|game| !game.unranked && game.outcome != Outcome::Won)
+            .map(|run| run.iter().filter(|game| !game.unranked).count())
+    }
+
+    pub fn current_streak(&self) -> usize {
+        self.streaks().last().unwrap_or(0)
+    }
+
+    pub fn max_streak(&self) -> usize {
+        self.streaks().max().unwrap_or(0)
+    }
+
+    pub fn played_daily(&self, day: i64) -> bool {
+        self.games.iter().any(|game| game.day == Some(day))
+    }
+
+    /// Number of daily puzzles in a row won up to and including `day`.
+    pub fn daily_streak(&self, day: i64) -> usize {
+        let won: HashSet<i64> = self
+            .ranked()
+            .filter(|game| game.outcome == Outcome::Won)
+            .filter_map(|game| game.day)
+            .collect();
+
+        (0..).take_while(|back| won.contains(&(day - back))).count()
+    }
+
+    /// Number of wins that took each number of guesses, from one to six, or up
+    /// to the most any win took with more guesses allowed.
+    pub fn distribution(&self) -> Vec<usize> {
+        let mut distribution = vec![0; 6];
+
+        for game in self.ranked() {
+            if game.outcome == Outcome::Won && game.guesses > 0 {
+                if game.guesses > distribution.len() {
+                    distribution.resize(game.guesses, 0);
+                }
+
+                distribution[game.guesses - 1] += 1;
+            }
+        }
+
+        distribution
+    }
+
+    /// Time spent across every game it was kept for.
+    pub fn play_time(&self) -> Duration {
+        Duration::from_secs(self.games.iter().filter_map(|game| game.seconds).sum())
+    }
+
+    /// Average number of games on the days any were played.
+    pub fn games_per_day(&self) -> f64 {
+        let days: HashSet<NaiveDate> = self.games.iter().map(|game| game.date).collect();
+        self.games.len() as f64 / days.len().max(1) as f64
+    }
+
+    /// The headline numbers on one line, as shown after each game.
+    pub fn summary(&self) -> String {
+        format!(
+            "Played {} · Win {}% · Streak {} · Max {}",
+            self.games.len(),
+            self.win_percentage(),
+            self.current_streak(),
+            self.max_streak(),
+        )
+    }
+
+    /// Plain text rundown of the numbers, as shown by `--stats`.
+    pub fn report(&self) -> String {
+        if self.games.is_empty() && self.marathon_best == 0 {
+            return String::from("No games played yet\n");
+        }
+
+        let mut report = self.overview();
+        report.push_str("\nGuess distribution:\n");
+
+        for (guesses, count) in (1..).zip(self.distribution()) {
+            report.push_str(&format!("{guesses}: {count}\n"));
+        }
+
+        report.push('\n');
+        report.push_str(&self.details());
+
+        // a breakdown of one kind would only repeat the numbers above
+        let kinds = self.kinds();
+
+        if kinds.len() > 1 {
+            report.push_str("\nBy kind of game:\n");
+
+            for kind in kinds {
+                let only = self.only(&kind);
+                report.push_str(&format!("{}: {}\n", kind.label(), only.summary()));
+            }
+        }
+
+        report
+    }
+
+    /// The part of the report above the guess distribution.
+    pub fn overview(&self) -> String {
+        format!(
+            "Played: {}\nWin %: {}\nCurrent streak: {}\nMax streak: {}\nRecent games: {}\n",
+            self.games.len(),
+            self.win_percentage(),
+            self.current_streak(),
+            self.max_streak(),
+            sparkline(&self.games),
+        )
+    }
+
+    /// The part of the report below the guess distribution.
+    pub fn details(&self) -> String {
+        let mut report = format!(
+            "You've played {} across {} games, {:.1} a day\n",
+            fmt_duration(self.play_time()),
+            self.games.len(),
+            self.games_per_day(),
+        );
+
+        if let Some(best) = fastest_win(&self.games) {
+            report.push_str(&format!(
+                "Fastest win: {}\n",
+                fmt_duration(Duration::from_secs(best))
+            ));
+        }
+
+        if self.marathon_best > 0 {
+            report.push_str(&format!("Best marathon: {} points\n", self.marathon_best));
+        }
+
+        if !self.missed.is_empty() {
+            report.push_str(&format!(
+                "{} missed words to practice with --review\n",
+                self.missed.len()
+            ));
+        }
+
+        report.push_str(&format!(
+            "\nAchievements ({} of {}):\n",
+            self.achievements.len(),
+            Achievement::ALL.len()
+        ));
+
+        for achievement in Achievement::ALL {
+            let mark = if self.achievements.contains(&achievement) {
+                '✓'
+            } else {
+                '·'
+            };
+
+            report.push_str(&format!(
+                "{mark} {}: {}\n",
+                achievement.name(),
+                achievement.description()
+            ));
+        }
+
+        report
+    }
+
+    pub fn export_heatmap_csv(&self, path: &Path) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        writeln!(file, "letter,green,yellow,grey")?;
+
+        for (c, [green, yellow, grey]) in letter_heatmap(&self.games) {
+            writeln!(file, "{c},{green},{yellow},{grey}")?;
+        }
+
+        file.flush()
+    }
+
+    pub fn export_csv(&self, path: &Path) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut file)?;
+        file.flush()
+    }
+
+    pub fn export_json(&self, path: &Path) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.write_json(&mut file)?;
+        file.flush()
+    }
+
+    /// Writes the headline numbers and every game as one JSON object.
+    pub fn write_json(&self, mut w: impl Write) -> io::Result<()> {
+        let by_kind: Vec<serde_json::Value> = self
+            .kinds()
+            .iter()
+            .map(|kind| {
+                let only = self.only(kind);
+
+                serde_json::json!({
+                    "mode": kind.mode,
+                    "letters": kind.letters,
+                    "hard": kind.hard,
+                    "played": only.games.len(),
+                    "wins": only.wins(),
+                    "win_percentage": only.win_percentage(),
+                    "current_streak": only.current_streak(),
+                    "max_streak": only.max_streak(),
+                    "distribution": only.distribution(),
+                })
+            })
+            .collect();
+
+        let export = serde_json::json!({
+            "played": self.games.len(),
+            "wins": self.wins(),
+            "win_percentage": self.win_percentage(),
+            "current_streak": self.current_streak(),
+            "max_streak": self.max_streak(),
+            "distribution": self.distribution(),
+            "seconds_played": self.play_time().as_secs(),
+            "achievements": self.achievements,
+            "marathon_best": self.marathon_best,
+            "by_kind": by_kind,
+            "games": self.games,
+        });
+
+        serde_json::to_writer_pretty(&mut w, &export)?;
+        writeln!(w)
+    }
+
+    pub fn write_csv(&self, mut w: impl Write) -> io::Result<()> {
+        writeln!(w, "date,answer,guesses,outcome")?;
+
+        for game in &self.games {
+            writeln!(
+                w,
+                "{},{},{},{}",
+                game.date,
+                csv_field(&game.answer),
+                game.guesses,
+                game.outcome.as_str(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A duration to the nearest minute once it is that long, such as `3h 12m`.
+pub fn fmt_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+
+    match (secs / 3600, secs / 60 % 60) {
+        (0, 0) => format!("{secs}s"),
+        (0, mins) => format!("{mins}m"),
+        (hours, mins) => format!("{hours}h {mins}m"),
+    }
+}
+
+/// Seconds taken by the quickest ranked win, among the games whose time was kept.
+pub fn fastest_win(games: &[GameRecord]) -> Option<u64> {
+    games
+        .iter()
+        .filter(|game| !game.unranked && game.outcome == Outcome::Won)
+        .filter_map(|game| game.seconds)
+        .min()
+}
+
+/// The guesses each of the last 20 games took as bars from `▁` for one to `▆`
+/// for six, with `×` for games that weren't won.
+pub fn sparkline(records: &[GameRecord]) -> String {
+    const BARS: [char; 6] = ['▁', '▂', '▃', '▄', '▅', '▆'];
+
+    let recent = &records[records.len().saturating_sub(20)..];
+
+    recent
+        .iter()
+        .map(|game| match game.outcome {
+            Outcome::Won => BARS[game.guesses.clamp(1, 6) - 1],
+            Outcome::Lost | Outcome::Forfeit => '×',
+        })
+        .collect()
+}
+
+/// How many times each guessed letter came up green, yellow and grey, in that
+/// order, across every game whose guesses were kept.
+pub fn letter_heatmap(games: &[GameRecord]) -> BTreeMap<char, [usize; 3]> {
+    let mut heatmap: BTreeMap<char, [usize; 3]> = BTreeMap::new();
+
+    for game in games {
+        for word in game.words.iter().filter(|word| !word.is_empty()) {
+            for (c, clue) in word.chars().zip(score_guess(&game.answer, word)) {
+                heatmap.entry(c).or_default()[clue as usize] += 1;
+            }
+        }
+    }
+
+    heatmap
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(answer: &str, guesses: usize, outcome: Outcome) -> GameRecord {
+        GameRecord {
+            date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            answer: answer.to_string(),
+            guesses,
+            outcome,
+            hints: 0,
+            words: Vec::new(),
+            day: None,
+            seconds: None,
+            hard: false,
+            mode: None,
+            unranked: false,
+        }
+    }
+
+    /// Reads a field back the way a CSV reader would.
+    fn unquote(field: &str) -> String {
+        match field
+            .strip_prefix('"')
+            .and_then(|field| field.strip_suffix('"'))
+        {
+            Some(quoted) => quoted.replace("\"\"", "\""),
+            None => field.to_string(),
+        }
+    }
+
+    #[test]
+    fn csv_fields_survive_quoting() {
+        for field in ["crane", "a,b", "say \"hi\"", "two\nlines", "\"", ""] {
+            assert_eq!(unquote(&csv_field(field)), field);
+        }
+
+        assert_eq!(csv_field("crane"), "crane");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+    }
+
+    #[test]
+    fn csv_has_a_row_per_game() {
+        let stats = Stats {
+            games: vec![
+                game("crane", 3, Outcome::Won),
+                game("a,b", 6, Outcome::Lost),
+            ],
+            ..Stats::default()
+        };
+
+        let mut csv = Vec::new();
+        stats.write_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "date,answer,guesses,outcome\n\
+             2024-03-01,crane,3,won\n\
+             2024-03-01,\"a,b\",6,lost\n"
+        );
+    }
+
+    #[test]
+    fn heatmap_counts_each_clue_a_letter_got() {
+        let mut won = game("crane", 2, Outcome::Won);
+        won.words = vec![String::from("react"), String::from("crane")];
+        // a burned row adds nothing
+        let mut lost = game("moist", 6, Outcome::Lost);
+        lost.words = vec![String::new(), String::from("crane")];
+
+        let heatmap = letter_heatmap(&[won, lost, game("older", 4, Outcome::Won)]);
+
+        // green, yellow, grey
+        assert_eq!(heatmap[&'c'], [1, 1, 1]);
+        assert_eq!(heatmap[&'r'], [1, 1, 1]);
+        assert_eq!(heatmap[&'t'], [0, 0, 1]);
+        assert_eq!(heatmap.len(), 6);
+    }
+
+    #[test]
+    fn sparkline_shows_the_last_twenty_games() {
+        let games = [
+            game("crane", 1, Outcome::Won),
+            game("crane", 4, Outcome::Won),
+            game("crane", 6, Outcome::Lost),
+            game("crane", 6, Outcome::Won),
+            game("crane", 2, Outcome::Forfeit),
+        ];
+        assert_eq!(sparkline(&games), "▁▄×▆×");
+        assert_eq!(sparkline(&[]), "");
+
+        let mut many = vec![game("crane", 6, Outcome::Won)];
+        many.extend((0..20).map(|_| game("crane", 2, Outcome::Won)));
+        assert_eq!(sparkline(&many), "▂".repeat(20));
+    }
+
+    #[test]
+    fn durations_show_their_largest_units() {
+        assert_eq!(fmt_duration(Duration::from_secs(42)), "42s");
+        assert_eq!(fmt_duration(Duration::from_secs(150)), "2m");
+        assert_eq!(
+            fmt_duration(Duration::from_secs(3 * 3600 + 5 * 60 + 9)),
+            "3h 5m"
+        );
+        assert_eq!(fmt_duration(Duration::from_secs(3600)), "1h 0m");
+    }
+
+    #[test]
+    fn play_time_adds_up_the_timed_games() {
+        let mut timed = game("crane", 3, Outcome::Won);
+        timed.seconds = Some(90);
+        let mut other = game("slate", 6, Outcome::Lost);
+        other.seconds = Some(30);
+
+        let stats = Stats {
+            games: vec![timed, game("moist", 4, Outcome::Won), other],
+            ..Stats::default()
+        };
+        assert_eq!(stats.play_time(), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn missed_answers_wait_until_solved() {
+        let mut stats = Stats::default();
+
+        stats.record_game(game("crane", 6, Outcome::Lost));
+        stats.record_game(game("moist", 3, Outcome::Forfeit));
+        stats.record_game(game("crane", 6, Outcome::Lost));
+        // given up on before a single guess
+        stats.record_game(game("slate", 0, Outcome::Forfeit));
+        assert_eq!(stats.missed, ["crane", "moist"]);
+
+        stats.record_game(game("crane", 4, Outcome::Won));
+        assert_eq!(stats.missed, ["moist"]);
+    }
+
+    #[test]
+    fn distribution_grows_for_longer_wins() {
+        let mut stats = Stats::default();
+        assert_eq!(stats.distribution(), [0; 6]);
+
+        stats.record_game(game("crane", 3, Outcome::Won));
+        stats.record_game(game("slate", 9, Outcome::Won));
+        stats.record_game(game("moist", 12, Outcome::Lost));
+
+        assert_eq!(stats.distribution(), [0, 0, 1, 0, 0, 0, 0, 0, 1]);
+        assert!(stats.report().contains("\n9: 1"));
+    }
+}
@@ -0,0 +1,412 @@
+use clap::ValueEnum;
+use crossterm::style::Color;
+use serde::Deserialize;
+
+use wordle::{upper, Clue};
+
+#[derive(Clone, Copy, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Classic,
+    HighContrast,
+    Solarized,
+    Mono,
+}
+
+/// The lines the grid is drawn with, every one of them keeping the cells four columns apart.
+#[derive(Clone, Copy, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BorderStyle {
+    #[default]
+    Double,
+    Rounded,
+    Thick,
+    Thin,
+    /// Plain `+`, `-` and `|`, for terminals and fonts that mangle box drawing.
+    Ascii,
+    /// Blank borders, leaving only the spacing between the tiles.
+    None,
+}
+
+impl BorderStyle {
+    /// The top, middle, in-between and bottom lines of a grid `tiles` tiles
+    /// wide, each four columns to a tile and one more.
+    pub fn rows(self, tiles: usize) -> [String; 4] {
+        self.pieces().map(|[left, fill, join, right]| {
+            let tile = fill.to_string().repeat(3);
+            let inner = vec![tile; tiles].join(&join.to_string());
+            format!("{left}{inner}{right}")
+        })
+    }
+
+    /// The left end, filling, join between tiles and right end of each line.
+    fn pieces(self) -> [[char; 4]; 4] {
+        match self {
+            BorderStyle::Double => [
+                ['╔', '═', '╦', '╗'],
+                ['║', ' ', '║', '║'],
+                ['╠', '═', '╬', '╣'],
+                ['╚', '═', '╩', '╝'],
+            ],
+            BorderStyle::Rounded => [
+                ['╭', '─', '┬', '╮'],
+                ['│', ' ', '│', '│'],
+                ['├', '─', '┼', '┤'],
+                ['╰', '─', '┴', '╯'],
+            ],
+            BorderStyle::Thick => [
+                ['┏', '━', '┳', '┓'],
+                ['┃', ' ', '┃', '┃'],
+                ['┣', '━', '╋', '┫'],
+                ['┗', '━', '┻', '┛'],
+            ],
+            BorderStyle::Thin => [
+                ['┌', '─', '┬', '┐'],
+                ['│', ' ', '│', '│'],
+                ['├', '─', '┼', '┤'],
+                ['└', '─', '┴', '┘'],
+            ],
+            BorderStyle::Ascii => [
+                ['+', '-', '+', '+'],
+                ['|', ' ', '|', '|'],
+                ['+', '-', '+', '+'],
+                ['+', '-', '+', '+'],
+            ],
+            BorderStyle::None => [[' '; 4]; 4],
+        }
+    }
+}
+
+/// Arrangement of the letters on the keyboard shown below the grid.
+#[derive(Clone, Copy, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    /// French, with the accented letters from the row of digits and ù.
+    Azerty,
+    /// With the German umlauts.
+    Qwertz,
+    /// Russian.
+    Jcuken,
+}
+
+impl KeyboardLayout {
+    /// The letters of each row, top to bottom.
+    pub fn rows(self) -> &'static [&'static str] {
+        match self {
+            KeyboardLayout::Qwerty => &["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Azerty => &["éèçà", "azertyuiop", "qsdfghjklmù", "wxcvbn"],
+            KeyboardLayout::Qwertz => &["qwertzuiopü", "asdfghjklöä", "yxcvbnm"],
+            KeyboardLayout::Jcuken => &["йцукенгшщзхъ", "фывапролджэ", "ячсмитьбю"],
+        }
+    }
+
+    /// Keys in the longest row.
+    pub fn width(self) -> usize {
+        self.rows()
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// How many colors the terminal can show.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ColorSupport {
+    /// Only the eight basic colors, which dark grey isn't one of.
+    #[value(name = "8")]
+    Basic,
+    /// The 256 color palette.
+    #[value(name = "256")]
+    Ansi256,
+    /// Any color, hex colors included.
+    #[value(name = "truecolor")]
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Guesses from `COLORTERM` and `TERM`. Terminals that don't say are taken
+    /// to manage 256 colors, as nearly every one in use does.
+    pub fn detect() -> Self {
+        let var = |name| std::env::var(name).unwrap_or_default();
+        let (colorterm, term) = (var("COLORTERM"), var("TERM"));
+
+        if matches!(colorterm.as_str(), "truecolor" | "24bit") || term.ends_with("-direct") {
+            ColorSupport::TrueColor
+        } else if term.contains("256color") {
+            ColorSupport::Ansi256
+        } else if term.is_empty()
+            || term.ends_with("-8color")
+            || ["dumb", "ansi", "cons25", "vt100", "vt102", "vt220"].contains(&term.as_str())
+        {
+            ColorSupport::Basic
+        } else {
+            ColorSupport::Ansi256
+        }
+    }
+}
+
+/// Every color the renderer uses, so the render code never picks its own.
+#[derive(Clone)]
+pub struct Theme {
+    pub green: Color,
+    pub yellow: Color,
+    pub grey: Color,
+    pub cursor: Color,
+    pub border: Color,
+    pub timer: Color,
+    pub warning: Color,
+    /// Background of the warmest guesses with `--warmth`, faded out for colder ones.
+    pub heat: Color,
+    /// Whether green and yellow tiles are also told apart by the brackets around
+    /// them, for when their colors can't be.
+    pub symbols: bool,
+}
+
+impl Theme {
+    pub fn preset(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Classic => Self {
+                green: Color::Green,
+                yellow: Color::Yellow,
+                grey: Color::DarkGrey,
+                cursor: Color::Reset,
+                border: Color::Reset,
+                timer: Color::Yellow,
+                warning: Color::Red,
+                heat: rgb(0x5f3a00),
+                symbols: false,
+            },
+
+            ThemeName::HighContrast => Self {
+                green: rgb(0xf5793a),
+                yellow: rgb(0x85c0f9),
+                grey: Color::DarkGrey,
+                cursor: Color::White,
+                border: Color::White,
+                timer: rgb(0x85c0f9),
+                warning: rgb(0xf5793a),
+                heat: rgb(0x4a2a5a),
+                symbols: false,
+            },
+
+            ThemeName::Solarized => Self {
+                green: rgb(0x859900),
+                yellow: rgb(0xb58900),
+                grey: rgb(0x586e75),
+                cursor: rgb(0x93a1a1),
+                border: rgb(0x657b83),
+                timer: rgb(0x268bd2),
+                warning: rgb(0xdc322f),
+                heat: rgb(0x073642),
+                symbols: false,
+            },
+
+            ThemeName::Mono => Self {
+                green: Color::White,
+                yellow: Color::Grey,
+                grey: Color::DarkGrey,
+                cursor: Color::White,
+                border: Color::Grey,
+                timer: Color::Grey,
+                warning: Color::White,
+                heat: rgb(0x444444),
+                symbols: true,
+            },
+        }
+    }
+
+    /// The theme brought down to what the terminal can show: hex colors become
+    /// their nearest in the 256 color palette, or with only eight colors their
+    /// nearest basic one, with grey left to the terminal's own text color and
+    /// tiles bracketed so that clues never hang on color alone.
+    pub fn limited(mut self, support: ColorSupport) -> Self {
+        let colors = [
+            &mut self.green,
+            &mut self.yellow,
+            &mut self.grey,
+            &mut self.cursor,
+            &mut self.border,
+            &mut self.timer,
+            &mut self.warning,
+            &mut self.heat,
+        ];
+
+        match support {
+            ColorSupport::TrueColor => {}
+            ColorSupport::Ansi256 => colors
+                .into_iter()
+                .for_each(|color| *color = ansi256(*color)),
+            ColorSupport::Basic => {
+                colors.into_iter().for_each(|color| *color = basic(*color));
+                self.symbols = true;
+            }
+        }
+
+        self
+    }
+
+    pub fn clue(&self, clue: Clue) -> Color {
+        match clue {
+            Clue::Green => self.green,
+            Clue::Yellow => self.yellow,
+            Clue::Grey => self.grey,
+        }
+    }
+
+    /// A guessed letter as the three columns of its tile, bracketed by its clue
+    /// when the theme uses symbols: `[A]` for green, `(A)` for yellow.
+    pub fn tile(&self, c: char, clue: Clue) -> String {
+        let c = upper(c);
+
+        match (self.symbols, clue) {
+            (true, Clue::Green) => format!("[{c}]"),
+            (true, Clue::Yellow) => format!("({c})"),
+            _ => format!(" {c} "),
+        }
+    }
+
+    /// Background for a guess as close to the answer as `closeness`, from 0 to 1.
+    pub fn tint(&self, closeness: f32) -> Option<Color> {
+        if closeness <= 0.0 {
+            return None;
+        }
+
+        match self.heat {
+            Color::Rgb { r, g, b } => {
+                let fade = |c: u8| (c as f32 * closeness).round() as u8;
+                Some(rgb(u32::from_be_bytes([0, fade(r), fade(g), fade(b)])))
+            }
+
+            // named colors can't be faded, so only the warmer half gets one
+            heat => (closeness >= 0.5).then_some(heat),
+        }
+    }
+}
+
+const fn rgb(hex: u32) -> Color {
+    let [_, r, g, b] = hex.to_be_bytes();
+    Color::Rgb { r, g, b }
+}
+
+/// The nearest color of the 6×6×6 cube in the 256 color palette to a hex color.
+fn ansi256(color: Color) -> Color {
+    match color {
+        Color::Rgb { r, g, b } => {
+            let level = |c: u8| (c as u16 * 5 + 127) / 255;
+            Color::AnsiValue((16 + 36 * level(r) + 6 * level(g) + level(b)) as u8)
+        }
+        color => color,
+    }
+}
+
+/// The nearest of the eight basic colors, or the terminal's own for the greys
+/// past them that could come out invisible.
+fn basic(color: Color) -> Color {
+    const BASIC: [(Color, [i32; 3]); 8] = [
+        (Color::Black, [0, 0, 0]),
+        (Color::DarkRed, [128, 0, 0]),
+        (Color::DarkGreen, [0, 128, 0]),
+        (Color::DarkYellow, [128, 128, 0]),
+        (Color::DarkBlue, [0, 0, 128]),
+        (Color::DarkMagenta, [128, 0, 128]),
+        (Color::DarkCyan, [0, 128, 128]),
+        (Color::Grey, [192, 192, 192]),
+    ];
+
+    match color {
+        Color::Rgb { r, g, b } => {
+            let distance = |[br, bg, bb]: [i32; 3]| {
+                (r as i32 - br).pow(2) + (g as i32 - bg).pow(2) + (b as i32 - bb).pow(2)
+            };
+
+            BASIC
+                .into_iter()
+                .min_by_key(|&(_, rgb)| distance(rgb))
+                .map_or(Color::Reset, |(color, _)| color)
+        }
+        Color::Red => Color::DarkRed,
+        Color::Green => Color::DarkGreen,
+        Color::Yellow => Color::DarkYellow,
+        Color::Blue => Color::DarkBlue,
+        Color::Magenta => Color::DarkMagenta,
+        Color::Cyan => Color::DarkCyan,
+        Color::White => Color::Grey,
+        Color::AnsiValue(n) if n < 8 => color,
+        Color::DarkGrey | Color::AnsiValue(_) => Color::Reset,
+        color => color,
+    }
+}
+
+/// The `[theme]` section of the config file: a preset, plus any colors to override in it.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeConfig {
+    pub preset: Option<ThemeName>,
+    pub green: Option<ThemeColor>,
+    pub yellow: Option<ThemeColor>,
+    pub grey: Option<ThemeColor>,
+    pub cursor: Option<ThemeColor>,
+    pub border: Option<ThemeColor>,
+    pub timer: Option<ThemeColor>,
+    pub warning: Option<ThemeColor>,
+    pub heat: Option<ThemeColor>,
+    pub symbols: Option<bool>,
+}
+
+impl ThemeConfig {
+    /// Builds the theme from `preset`, or the configured preset if none was given.
+    pub fn theme(&self, preset: Option<ThemeName>) -> Theme {
+        let mut theme = Theme::preset(preset.or(self.preset).unwrap_or_default());
+
+        let overrides = [
+            (&mut theme.green, self.green),
+            (&mut theme.yellow, self.yellow),
+            (&mut theme.grey, self.grey),
+            (&mut theme.cursor, self.cursor),
+            (&mut theme.border, self.border),
+            (&mut theme.timer, self.timer),
+            (&mut theme.warning, self.warning),
+            (&mut theme.heat, self.heat),
+        ];
+
+        for (color, custom) in overrides {
+            if let Some(ThemeColor(custom)) = custom {
+                *color = custom;
+            }
+        }
+
+        if let Some(symbols) = self.symbols {
+            theme.symbols = symbols;
+        }
+
+        theme
+    }
+}
+
+/// A color in the config file, either a name (`dark_grey`) or a hex code (`#859900`).
+#[derive(Clone, Copy, Deserialize)]
+#[serde(try_from = "String")]
+pub struct ThemeColor(pub Color);
+
+impl TryFrom<String> for ThemeColor {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        if let Some(hex) = name.strip_prefix('#') {
+            let hex_rgb = u32::from_str_radix(hex, 16)
+                .ok()
+                .filter(|_| hex.len() == 6)
+                .ok_or_else(|| format!("invalid hex color `{name}`"))?;
+
+            return Ok(ThemeColor(rgb(hex_rgb)));
+        }
+
+        Color::try_from(name.as_str())
+            .map(ThemeColor)
+            .map_err(|_| format!("unknown color `{name}`"))
+    }
+}
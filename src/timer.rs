@@ -0,0 +1,70 @@
+use std::time::{Duration, Instant};
+
+/// Measures elapsed time, leaving out any time spent paused.
+pub struct Stopwatch {
+    start: Instant,
+    paused_at: Option<Instant>,
+    paused: Duration,
+}
+
+impl Stopwatch {
+    pub fn start() -> Self {
+        Self {
+            start: Instant::now(),
+            paused_at: None,
+            paused: Duration::ZERO,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        let now = self.paused_at.unwrap_or_else(Instant::now);
+        now.duration_since(self.start).saturating_sub(self.paused)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    pub fn pause(&mut self) {
+        self.paused_at.get_or_insert_with(Instant::now);
+    }
+
+    pub fn resume(&mut self) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.paused += paused_at.elapsed();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAP: Duration = Duration::from_millis(200);
+
+    #[test]
+    fn paused_time_is_left_out() {
+        let mut stopwatch = Stopwatch::start();
+        stopwatch.pause();
+        let at_pause = stopwatch.elapsed();
+
+        std::thread::sleep(NAP);
+        assert!(stopwatch.is_paused());
+        assert_eq!(stopwatch.elapsed(), at_pause);
+
+        // pausing again doesn't move the moment it was paused at
+        stopwatch.pause();
+        stopwatch.resume();
+        assert!(!stopwatch.is_paused());
+        assert!(stopwatch.elapsed() < NAP);
+    }
+
+    #[test]
+    fn running_time_is_counted() {
+        let mut stopwatch = Stopwatch::start();
+        stopwatch.resume();
+
+        std::thread::sleep(NAP);
+        assert!(stopwatch.elapsed() >= NAP);
+    }
+}
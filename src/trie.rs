@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+/// Prefix tree over a word list, answering membership and prefix queries
+/// without scanning the list.
+#[derive(Default)]
+pub struct WordTrie {
+    children: BTreeMap<char, WordTrie>,
+    is_word: bool,
+}
+
+impl WordTrie {
+    pub fn insert(&mut self, word: &str) {
+        let node = word
+            .chars()
+            .fold(self, |node, c| node.children.entry(c).or_default());
+
+        node.is_word = true;
+    }
+
+    fn find(&self, prefix: &str) -> Option<&WordTrie> {
+        prefix
+            .chars()
+            .try_fold(self, |node, c| node.children.get(&c))
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.is_word)
+    }
+
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    /// The only word starting with `prefix`, if exactly one does.
+    pub fn unique_completion(&self, prefix: &str) -> Option<String> {
+        let mut node = self.find(prefix)?;
+        let mut word = prefix.to_string();
+
+        while !node.is_word && node.children.len() == 1 {
+            let (&c, child) = node.children.iter().next()?;
+            word.push(c);
+            node = child;
+        }
+
+        (node.is_word && node.children.is_empty()).then_some(word)
+    }
+}
+
+impl<'a> FromIterator<&'a str> for WordTrie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(words: I) -> Self {
+        let mut trie = Self::default();
+        words.into_iter().for_each(|word| trie.insert(word));
+        trie
+    }
+}
@@ -0,0 +1,115 @@
+//! Newer answer and guess lists downloaded by `wordle update-words`, which
+//! take the place of the built-in ones from then on.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use wordle::WordleError;
+
+/// Where the lists are downloaded from unless the config file says otherwise,
+/// with `/` and the name of each list appended.
+pub const DEFAULT_URL: &str = "https://raw.githubusercontent.com/meliosu/wordle/main";
+
+/// How long to wait for each list before giving up, in seconds.
+const TIMEOUT: &str = "30";
+
+/// The lists that can be updated, the answers first.
+pub const NAMES: [&str; 2] = ["answers", "guesses"];
+
+/// Where the downloaded list called `name` is kept, usually
+/// `~/.local/share/wordle/answers`, which is also where builds with the
+/// `runtime-wordlists` feature look for their lists.
+pub fn path(name: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("wordle").join(name))
+}
+
+/// The downloaded list called `name`, if it has been downloaded.
+#[cfg(not(feature = "runtime-wordlists"))]
+pub fn downloaded(name: &str) -> Option<PathBuf> {
+    path(name).filter(|path| path.exists())
+}
+
+/// Downloads every list from `url`, checks them and saves them over the ones
+/// downloaded before, giving the number of words in each. Nothing is saved
+/// unless every list is fine, so that they always agree with each other.
+pub fn update(url: &str) -> Result<Vec<(&'static str, usize)>, WordleError> {
+    let mut lists = Vec::new();
+
+    for name in NAMES {
+        let url = format!("{}/{name}", url.trim_end_matches('/'));
+        let list = fetch(&url)?;
+
+        if let Some((line, word)) = (1..)
+            .zip(list.lines())
+            .find(|(_, word)| !wordle::is_list_word(word))
+        {
+            return Err(WordleError::BadEntry {
+                list: url,
+                line,
+                entry: word.to_string(),
+            });
+        }
+
+        if list.trim().is_empty() {
+            return Err(WordleError::EmptyList(url));
+        }
+
+        lists.push((name, list));
+    }
+
+    let guesses: HashSet<&str> = lists[1].1.lines().collect();
+
+    if let Some(answer) = lists[0].1.lines().find(|answer| !guesses.contains(answer)) {
+        return Err(WordleError::Unguessable(answer.to_string()));
+    }
+
+    for (name, list) in &lists {
+        let path = path(name).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "couldn't find the data directory")
+        })?;
+        let saved = |source| WordleError::File {
+            path: path.clone(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(saved)?;
+        }
+
+        // written alongside first, so that a failed write leaves the old list whole
+        let new = path.with_extension("new");
+        fs::write(&new, list).map_err(saved)?;
+        fs::rename(&new, &path).map_err(saved)?;
+    }
+
+    Ok(lists
+        .iter()
+        .map(|(name, list)| (*name, list.lines().count()))
+        .collect())
+}
+
+/// Downloads `url` through `curl`, which has to be installed.
+fn fetch(url: &str) -> Result<String, WordleError> {
+    let failed = |reason: String| WordleError::Download {
+        url: url.to_string(),
+        reason,
+    };
+
+    let output = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location"])
+        .args(["--max-time", TIMEOUT])
+        .arg(url)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| failed(format!("couldn't run curl, is it installed? ({err})")))?;
+
+    if !output.status.success() {
+        let why = String::from_utf8_lossy(&output.stderr);
+        return Err(failed(why.trim().to_string()));
+    }
+
+    String::from_utf8(output.stdout).map_err(|_| failed(String::from("the list isn't UTF-8")))
+}
@@ -0,0 +1,1832 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::Mutex;
+
+use chrono::NaiveDate;
+use lazy_static::lazy_static;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::error::WordleError;
+use crate::pattern::{ascii_word, score};
+use crate::trie::WordTrie;
+
+/// The contents of one of the word list files next to `Cargo.toml`, unless
+/// `use_custom_list` put another in its place.
+#[cfg(not(feature = "runtime-wordlists"))]
+macro_rules! word_list {
+    ($name:literal) => {
+        custom_list($name).unwrap_or_else(|| include_str!(concat!("../", $name)))
+    };
+}
+
+#[cfg(feature = "runtime-wordlists")]
+macro_rules! word_list {
+    ($name:literal) => {
+        custom_list($name).unwrap_or_else(|| crate::wordlists::load($name))
+    };
+}
+
+lazy_static! {
+    /// Lists given in place of the usual ones, by name.
+    static ref CUSTOM_LISTS: Mutex<HashMap<&'static str, &'static str>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Plays with `list` in place of the word list called `name`, such as `answers`.
+/// Only has an effect before the list is first used.
+pub fn use_custom_list(name: &'static str, list: &'static str) {
+    CUSTOM_LISTS.lock().unwrap().insert(name, list);
+}
+
+fn custom_list(name: &str) -> Option<&'static str> {
+    CUSTOM_LISTS.lock().unwrap().get(name).copied()
+}
+
+/// The capital of `c` for showing it, or `c` itself when it has no capital of
+/// a single letter, as with `ß`.
+pub fn upper(c: char) -> char {
+    let mut upper = c.to_uppercase();
+
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => c,
+    }
+}
+
+/// [`upper`] for a whole letter, which may be made up of several characters
+/// such as a letter and an accent.
+pub fn upper_letter(letter: &str) -> String {
+    letter.chars().map(upper).collect()
+}
+
+/// How many letters the words of a game can have.
+pub const WORD_LENGTHS: RangeInclusive<usize> = 4..=8;
+
+/// Whether `word` can go in a word list: four to eight lowercase letters, from
+/// any alphabet, accents written as combining marks included.
+pub fn is_list_word(word: &str) -> bool {
+    WORD_LENGTHS.contains(&letter_count(word))
+        && word.graphemes(true).all(|letter| {
+            letter
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphabetic() && !c.is_uppercase())
+        })
+}
+
+/// How many letters `word` has, counting each grapheme cluster once, so that
+/// a letter with a combining accent is one letter and not two.
+pub fn letter_count(word: &str) -> usize {
+    word.graphemes(true).count()
+}
+
+/// `word` with `c` typed after it, if `c` is a letter or an accent going on the
+/// letter before it, which then takes the composed form the word lists use
+/// where there is one.
+pub(crate) fn typed_into(word: &str, c: char) -> Option<String> {
+    let typed: String = word.chars().chain(c.to_lowercase()).nfc().collect();
+    let accent = !word.is_empty() && letter_count(&typed) == letter_count(word);
+    (c.is_alphabetic() || accent).then_some(typed)
+}
+
+/// Takes the last letter off `word`, along with any accents on it.
+pub(crate) fn erase_letter(word: &mut String) {
+    if let Some((idx, _)) = word.grapheme_indices(true).next_back() {
+        word.truncate(idx);
+    }
+}
+
+/// Plays with the bundled lists of the language with the ISO 639-1 `code`,
+/// such as `fr`, in place of the English ones. Only has an effect before the
+/// lists are first used.
+pub fn use_language(code: &str) {
+    let (answers, guesses) = match code {
+        "fr" => (word_list!("answers-fr"), word_list!("guesses-fr")),
+        _ => return,
+    };
+
+    use_custom_list("answers", answers);
+    use_custom_list("guesses", guesses);
+    use_custom_list("common", guesses);
+}
+
+/// Plays with the bundled lists of `length`-letter words in place of the
+/// five-letter ones, their guesses also standing in for the everyday words.
+/// Only has an effect before the lists are first used.
+pub fn use_length(length: usize) {
+    let (answers, guesses) = match length {
+        4 => (word_list!("answers-4"), word_list!("guesses-4")),
+        6 => (word_list!("answers-6"), word_list!("guesses-6")),
+        7 => (word_list!("answers-7"), word_list!("guesses-7")),
+        8 => (word_list!("answers-8"), word_list!("guesses-8")),
+        _ => return,
+    };
+
+    use_custom_list("answers", answers);
+    use_custom_list("guesses", guesses);
+    use_custom_list("common", guesses);
+}
+
+/// How many letters the words of the lists in play have.
+pub fn word_length() -> usize {
+    ANSWERS.first().map_or(5, |answer| letter_count(answer))
+}
+
+lazy_static! {
+    pub static ref GUESS_LIST: Vec<&'static str> = word_list!("guesses").lines().collect();
+    pub static ref GUESSES: WordTrie = GUESS_LIST.iter().copied().collect();
+    pub static ref ANSWERS: Vec<&'static str> = word_list!("answers").lines().collect();
+    static ref ANSWER_TRIE: WordTrie = ANSWERS.iter().copied().collect();
+    /// Every letter used by the guess and answer lists, in whatever alphabet
+    /// they are written.
+    pub static ref ALPHABET: BTreeSet<char> = GUESS_LIST
+        .iter()
+        .chain(ANSWERS.iter())
+        .flat_map(|word| word.chars())
+        .collect();
+    static ref COMMON: Vec<&'static str> = word_list!("common").lines().collect();
+    static ref COMMON_TRIE: WordTrie = COMMON.iter().copied().collect();
+    static ref CATEGORIES: HashMap<&'static str, &'static str> = word_list!("categories")
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .collect();
+    static ref DEFINITIONS: HashMap<&'static str, &'static str> = word_list!("definitions")
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .collect();
+    static ref RUDE: HashSet<&'static str> = word_list!("rude").lines().collect();
+    static ref FREQUENCIES: HashMap<&'static str, u32> = word_list!("frequencies")
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .filter_map(|(word, rank)| Some((word, rank.parse().ok()?)))
+        .collect();
+}
+
+/// Whether `word` is too rude or hurtful to be picked as an answer, unless
+/// that is asked for. It can still be guessed.
+pub fn is_rude(word: &str) -> bool {
+    RUDE.contains(word)
+}
+
+/// The answer at `start` in `answers`, or if it is rude, unless `allow_rude`,
+/// or already `taken`, the next one along that isn't, wrapping around. Moving
+/// on from a pick rather than picking from a list without those words keeps
+/// every other pick, such as what a seed shared before picks, where it was.
+pub fn next_allowed<'a>(
+    answers: &[&'a str],
+    start: usize,
+    allow_rude: bool,
+    taken: &[&str],
+) -> &'a str {
+    (start..start + answers.len())
+        .map(|idx| answers[idx % answers.len()])
+        .find(|answer| (allow_rude || !is_rude(answer)) && !taken.contains(answer))
+        .unwrap_or(answers[start])
+}
+
+/// The day of daily puzzle 0, the same as the original game's.
+const FIRST_DAY: NaiveDate = match NaiveDate::from_ymd_opt(2021, 6, 19) {
+    Some(day) => day,
+    None => panic!(),
+};
+
+/// Seed for the order daily puzzles go through the answers in. Without a salt
+/// it is the public order, while each salt gives its own, by an FNV-1a hash
+/// that stays the same across builds.
+pub fn daily_seed(salt: Option<&str>) -> u64 {
+    let Some(salt) = salt else {
+        return 0;
+    };
+
+    salt.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Number of the daily puzzle for `date`.
+pub fn daily_number(date: NaiveDate) -> i64 {
+    (date - FIRST_DAY).num_days()
+}
+
+/// Checks that there are answers to pick and guesses to make, and that every
+/// answer can be guessed, which custom and downloaded lists might not have
+/// made sure of. Games can't start without it.
+pub fn check_lists() -> Result<(), WordleError> {
+    if ANSWERS.is_empty() {
+        return Err(WordleError::EmptyList(String::from("answers")));
+    }
+
+    if GUESS_LIST.is_empty() {
+        return Err(WordleError::EmptyList(String::from("guesses")));
+    }
+
+    let length = word_length();
+
+    for (list, words) in [("answers", &*ANSWERS), ("guesses", &*GUESS_LIST)] {
+        if let Some(word) = words.iter().find(|word| letter_count(word) != length) {
+            return Err(WordleError::MixedLengths {
+                list: list.to_string(),
+                word: word.to_string(),
+                length,
+            });
+        }
+    }
+
+    match ANSWERS.iter().find(|answer| !GUESSES.contains(answer)) {
+        Some(answer) => Err(WordleError::Unguessable(answer.to_string())),
+        None => Ok(()),
+    }
+}
+
+/// A word in the common list that isn't a valid guess, which would mean the list is broken.
+pub fn uncommon_common_word() -> Option<&'static str> {
+    COMMON.iter().copied().find(|word| !GUESSES.contains(word))
+}
+
+/// Answers picked by hand for particular days, taking the place of the daily
+/// order on those days.
+#[derive(Clone, Default, Debug)]
+pub struct Schedule(BTreeMap<NaiveDate, String>);
+
+impl Schedule {
+    /// Reads lines of a `YYYY-MM-DD` date and an answer separated by a tab,
+    /// skipping blank lines and `#` comments.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut days = BTreeMap::new();
+
+        for (number, line) in (1..).zip(text.lines()) {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (date, answer) = line.split_once('\t').ok_or_else(|| {
+                format!("line {number}: expected a date and an answer separated by a tab")
+            })?;
+
+            let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+                .map_err(|_| format!("line {number}: `{date}` is not a YYYY-MM-DD date"))?;
+
+            let answer = answer.trim().to_lowercase();
+
+            if !GUESSES.contains(&answer) {
+                return Err(format!("line {number}: `{answer}` is not a valid guess"));
+            }
+
+            if days.insert(date, answer).is_some() {
+                return Err(format!("line {number}: {date} is scheduled twice"));
+            }
+        }
+
+        Ok(Self(days))
+    }
+
+    pub fn get(&self, date: NaiveDate) -> Option<&str> {
+        self.0.get(&date).map(String::as_str)
+    }
+}
+
+/// Looks up a loose category (`animal`, `food`, ...) for a word, if one is known.
+pub fn category(word: &str) -> Option<&'static str> {
+    CATEGORIES.get(word).copied()
+}
+
+/// A short definition of a word, for the less familiar answers that have one.
+pub fn definition(word: &str) -> Option<&'static str> {
+    DEFINITIONS.get(word).copied()
+}
+
+/// Where a word comes among the most used English words, from 1 for the most
+/// used, for answers common enough to be ranked at all.
+pub fn frequency_rank(word: &str) -> Option<u32> {
+    FREQUENCIES.get(word).copied()
+}
+
+/// Every answer in the given category.
+pub fn words_in_category(tag: &str) -> Vec<&'static str> {
+    ANSWERS
+        .iter()
+        .copied()
+        .filter(|word| category(word) == Some(tag))
+        .collect()
+}
+
+/// All the categories words are tagged with.
+pub fn categories() -> BTreeSet<&'static str> {
+    CATEGORIES.values().copied().collect()
+}
+
+/// A word that was guessed, along with the clue each of its letters got.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Guess {
+    pub word: String,
+    pub clues: Vec<Clue>,
+}
+
+/// What became of a submitted guess.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GuessResult {
+    Accepted,
+    TooShort,
+    TooLong,
+    NotAWord,
+    /// Uses a letter banned from guesses.
+    Banned(char),
+    /// Leaves out, in hard mode, the letter found green at this position.
+    MissingGreen(usize, String),
+    /// Leaves out, in hard mode, a letter found in the answer.
+    MissingLetter(String),
+    /// Uses, in ultra-hard mode, more copies of a letter found grey than the
+    /// answer was found to have, which are this many.
+    GreyLetter(String, usize),
+    /// Puts, in ultra-hard mode, a letter back where it was found yellow.
+    YellowSpot(usize, String),
+    /// Was already guessed this game.
+    Repeated,
+}
+
+impl GuessResult {
+    pub fn is_accepted(&self) -> bool {
+        *self == GuessResult::Accepted
+    }
+
+    /// Why the guess was turned down, as a short tag such as `not-a-word`.
+    pub fn rejection(&self) -> Option<&'static str> {
+        match self {
+            GuessResult::Accepted => None,
+            GuessResult::TooShort => Some("too-short"),
+            GuessResult::TooLong => Some("too-long"),
+            GuessResult::NotAWord => Some("not-a-word"),
+            GuessResult::Banned(_) => Some("banned-letter"),
+            GuessResult::MissingGreen(..) => Some("missing-green"),
+            GuessResult::MissingLetter(_) => Some("missing-letter"),
+            GuessResult::GreyLetter(..) => Some("grey-letter"),
+            GuessResult::YellowSpot(..) => Some("yellow-spot"),
+            GuessResult::Repeated => Some("repeated"),
+        }
+    }
+}
+
+/// Ordered from most to least informative.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Clue {
+    Green,
+    Yellow,
+    Grey,
+}
+
+impl Clue {
+    pub const ALL: [Clue; 3] = [Clue::Green, Clue::Yellow, Clue::Grey];
+
+    pub fn letter(self) -> char {
+        match self {
+            Clue::Green => 'G',
+            Clue::Yellow => 'Y',
+            Clue::Grey => 'B',
+        }
+    }
+
+    /// The clue's color in words.
+    pub fn name(self) -> &'static str {
+        match self {
+            Clue::Green => "green",
+            Clue::Yellow => "yellow",
+            Clue::Grey => "grey",
+        }
+    }
+
+    /// The clue written as `letter` does it, if it is one.
+    pub fn from_letter(letter: char) -> Option<Self> {
+        Clue::ALL.into_iter().find(|clue| clue.letter() == letter)
+    }
+
+    pub fn emoji(self) -> char {
+        match self {
+            Clue::Green => '🟩',
+            Clue::Yellow => '🟨',
+            Clue::Grey => '⬛',
+        }
+    }
+
+    /// A plain ASCII stand-in for the emoji, for places that mangle emoji.
+    pub fn ascii(self) -> char {
+        match self {
+            Clue::Green => '#',
+            Clue::Yellow => '+',
+            Clue::Grey => '.',
+        }
+    }
+}
+
+/// Scores a guess against the answer the way the original game does: greens
+/// are matched first, then every other letter turns yellow only while the
+/// answer still has an unmatched copy of it.
+pub fn score_guess(answer: &str, guess: &str) -> Vec<Clue> {
+    if let (Some(answer), Some(guess)) = (ascii_word(answer), ascii_word(guess)) {
+        if answer.len() == guess.len() {
+            return score(guess, answer).clues(guess.len());
+        }
+    }
+
+    let mut clues = vec![Clue::Grey; letter_count(guess)];
+    let mut unmatched = Vec::new();
+
+    for (idx, (a, g)) in answer
+        .graphemes(true)
+        .zip(guess.graphemes(true))
+        .enumerate()
+    {
+        if a == g {
+            clues[idx] = Clue::Green;
+        } else {
+            unmatched.push(a);
+        }
+    }
+
+    for (idx, g) in guess.graphemes(true).enumerate() {
+        if clues[idx] == Clue::Green {
+            continue;
+        }
+
+        if let Some(pos) = unmatched.iter().position(|&a| a == g) {
+            clues[idx] = Clue::Yellow;
+            unmatched.swap_remove(pos);
+        }
+    }
+
+    clues
+}
+
+/// Whether `clues` are all green, as the answer gets.
+pub fn is_win(clues: &[Clue]) -> bool {
+    clues.iter().all(|&clue| clue == Clue::Green)
+}
+
+/// How many letters the words share, counting repeated letters only as often
+/// as they appear in both, as Jotto scores a guess.
+pub fn letters_in_common(answer: &str, guess: &str) -> usize {
+    let mut unmatched: Vec<char> = answer.chars().collect();
+
+    guess
+        .chars()
+        .filter(|g| match unmatched.iter().position(|a| a == g) {
+            Some(pos) => {
+                unmatched.swap_remove(pos);
+                true
+            }
+            None => false,
+        })
+        .count()
+}
+
+/// How close feedback came to the answer, from 0 for all grey to 1 for all
+/// green, with yellows counting half.
+pub fn closeness(clues: &[Clue]) -> f32 {
+    let score: f32 = clues
+        .iter()
+        .map(|clue| match clue {
+            Clue::Green => 1.0,
+            Clue::Yellow => 0.5,
+            Clue::Grey => 0.0,
+        })
+        .sum();
+
+    score / clues.len() as f32
+}
+
+/// Reads the rows of a shared emoji grid, skipping any line that isn't a row
+/// of four to eight squares, such as the header. Dark and light grey squares
+/// both count as grey, and the high contrast orange and blue stand for green
+/// and yellow.
+pub fn parse_emoji_grid(s: &str) -> Vec<Vec<Clue>> {
+    s.lines()
+        .filter_map(|line| {
+            let clues: Vec<Clue> = line
+                .trim()
+                .chars()
+                .map(|c| match c {
+                    '🟩' | '🟧' => Some(Clue::Green),
+                    '🟨' | '🟦' => Some(Clue::Yellow),
+                    '⬛' | '⬜' => Some(Clue::Grey),
+                    _ => None,
+                })
+                .collect::<Option<_>>()?;
+
+            WORD_LENGTHS.contains(&clues.len()).then_some(clues)
+        })
+        .collect()
+}
+
+/// The other words in `answers` made of exactly the same letters as `word`.
+pub fn anagrams<'a>(word: &str, answers: &[&'a str]) -> Vec<&'a str> {
+    let sorted = |word: &str| {
+        let mut letters: Vec<char> = word.chars().collect();
+        letters.sort_unstable();
+        letters
+    };
+
+    let letters = sorted(word);
+
+    answers
+        .iter()
+        .copied()
+        .filter(|&answer| answer != word && sorted(answer) == letters)
+        .collect()
+}
+
+/// How many other words in `answers` are anagrams of `word`.
+pub fn anagram_count(word: &str, answers: &[&str]) -> usize {
+    anagrams(word, answers).len()
+}
+
+/// Writes clues as a compact `GYBBB` string.
+pub fn clues_to_string(clues: &[Clue]) -> String {
+    clues.iter().map(|clue| clue.letter()).collect()
+}
+
+/// Answers that would have produced exactly this feedback for every guess.
+pub fn possible_answers(history: &[(&str, Vec<Clue>)]) -> Vec<&'static str> {
+    ANSWERS
+        .iter()
+        .copied()
+        .filter(|answer| {
+            history
+                .iter()
+                .all(|(guess, clues)| score_guess(answer, guess) == *clues)
+        })
+        .collect()
+}
+
+/// Splits the candidates by the feedback they would give for `guess` and keeps the
+/// largest group, preferring feedback with fewer greens and then fewer yellows.
+///
+/// `candidates` must not be empty. The group kept never is, and it only comes back
+/// all green once `guess` is the one candidate left, as any other group beats it.
+pub fn adversarial_feedback(
+    candidates: &[&'static str],
+    guess: &str,
+) -> (Vec<Clue>, Vec<&'static str>) {
+    let mut buckets: HashMap<Vec<Clue>, Vec<&'static str>> = HashMap::new();
+
+    for &candidate in candidates {
+        buckets
+            .entry(score_guess(candidate, guess))
+            .or_default()
+            .push(candidate);
+    }
+
+    let (clues, bucket) = buckets
+        .into_iter()
+        .max_by_key(|(clues, bucket)| {
+            let count = |kind| clues.iter().filter(|&&clue| clue == kind).count();
+            (
+                bucket.len(),
+                Reverse(count(Clue::Green)),
+                Reverse(count(Clue::Yellow)),
+                Reverse(clues.clone()),
+            )
+        })
+        .expect("no candidates to dodge the guess with");
+
+    debug_assert!(
+        !is_win(&clues) || candidates == [guess],
+        "gave all green to `{guess}` while other answers were still possible"
+    );
+
+    (clues, bucket)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Hint {
+    Category,
+    Anagram,
+    /// One letter of the answer, which can be asked for again to reveal another.
+    Letter,
+}
+
+/// Which words a game accepts as guesses.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum WordList {
+    /// Every known word, obscure ones included.
+    #[default]
+    Full,
+    /// The answers plus everyday words such as plurals.
+    Common,
+    /// Only the words that can be answers.
+    Answers,
+}
+
+#[derive(Clone)]
+pub struct Wordle {
+    pub answer: String,
+    pub curr: String,
+    /// Only ever changed along with `clues`, so that the two stay in step.
+    guesses: Vec<String>,
+    /// The clues each guess got, worked out once as it was made, with `None`
+    /// for burned rows.
+    clues: Vec<Option<Vec<Clue>>>,
+    pub hints: usize,
+    /// Number of guesses taken back with `undo`.
+    pub undos: usize,
+    pub seed: Option<u64>,
+    /// Number of the daily puzzle, if this is one.
+    pub day: Option<i64>,
+    /// The words accepted as guesses.
+    pub allowed: WordList,
+    /// Answers still in play when the game is dodging the player (absurdle); `answer`
+    /// then only stands for one of them.
+    candidates: Option<Vec<&'static str>>,
+    /// Most hints that can be taken, if limited.
+    pub hint_budget: Option<usize>,
+    hints_shown: HashSet<Hint>,
+    /// Positions of the answer given away by letter hints.
+    revealed: BTreeSet<usize>,
+    /// Letters no guess may use.
+    pub banned: BTreeSet<char>,
+    /// Start of every guess, filled in ahead of time and kept from being erased.
+    locked: String,
+    /// Whether every guess has to use what the guesses before it found.
+    pub hard: bool,
+    /// Whether, on top of hard mode, guesses also have to steer clear of what
+    /// was ruled out: grey letters, and yellow letters where they were found.
+    pub ultra_hard: bool,
+    /// Guesses allowed before the game is lost.
+    pub max_guesses: usize,
+}
+
+impl Wordle {
+    /// Starts a random game, through a fresh seed so that it can be replayed later.
+    #[cfg(feature = "random")]
+    pub fn new() -> Self {
+        Self::with_seed(rand::random())
+    }
+
+    /// Starts a game against an answer picked by `seed`, never a rude one.
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_seed_from(seed, &ANSWERS, false)
+    }
+
+    /// Starts a game against an answer picked from `answers` by `seed`, moving
+    /// on from a rude one unless `allow_rude`.
+    ///
+    /// `answers` must not be empty.
+    pub fn with_seed_from(seed: u64, answers: &[&str], allow_rude: bool) -> Self {
+        let picked = answers.choose(&mut StdRng::seed_from_u64(seed)).unwrap();
+        let start = answers.iter().position(|answer| answer == picked).unwrap();
+        let answer = next_allowed(answers, start, allow_rude, &[]);
+
+        Self {
+            seed: Some(seed),
+            ..Self::with_answer(answer)
+        }
+    }
+
+    /// Starts a game against `answer`, which is lowercased to match how guesses are typed.
+    pub fn with_answer(answer: &str) -> Self {
+        Self {
+            answer: answer.to_lowercase(),
+            curr: String::new(),
+            guesses: Vec::new(),
+            clues: Vec::new(),
+            hints: 0,
+            undos: 0,
+            seed: None,
+            day: None,
+            allowed: WordList::Full,
+            candidates: None,
+            hint_budget: None,
+            hints_shown: HashSet::new(),
+            revealed: BTreeSet::new(),
+            banned: BTreeSet::new(),
+            locked: String::new(),
+            hard: false,
+            ultra_hard: false,
+            max_guesses: 6,
+        }
+    }
+
+    /// The daily puzzle for `date`, the same for everyone playing that day with
+    /// the same salt. Unless `allow_rude`, a day whose answer is rude gets the
+    /// next one in the order that isn't.
+    pub fn daily(date: NaiveDate, salt: Option<&str>, allow_rude: bool) -> Self {
+        // shuffled so that the puzzles don't follow the alphabet
+        let mut answers = ANSWERS.clone();
+        answers.shuffle(&mut StdRng::seed_from_u64(daily_seed(salt)));
+
+        let day = daily_number(date);
+        let start = day.rem_euclid(answers.len() as i64) as usize;
+        let answer = next_allowed(&answers, start, allow_rude, &[]);
+
+        Self {
+            day: Some(day),
+            ..Self::with_answer(answer)
+        }
+    }
+
+    /// The daily puzzle for `date` with an answer picked by hand.
+    pub fn scheduled(date: NaiveDate, answer: &str) -> Self {
+        Self {
+            day: Some(daily_number(date)),
+            ..Self::with_answer(answer)
+        }
+    }
+
+    /// A game that never settles on an answer until it is forced to.
+    pub fn absurdle() -> Self {
+        Self {
+            candidates: Some(ANSWERS.clone()),
+            ..Self::with_answer(ANSWERS[0])
+        }
+    }
+
+    /// How many answers an absurdle game can still settle on, or `None` for a
+    /// game whose answer is fixed.
+    pub fn candidates_left(&self) -> Option<usize> {
+        self.candidates.as_ref().map(Vec::len)
+    }
+
+    /// Whether the answer has a banned letter, so that it can never be guessed.
+    pub fn answer_is_banned(&self) -> bool {
+        self.answer.chars().any(|c| self.banned.contains(&c))
+    }
+
+    /// Letters in the answer, and so in every guess.
+    pub fn length(&self) -> usize {
+        letter_count(&self.answer)
+    }
+
+    /// Number of different letters in the answer, fewer than its length when
+    /// some repeat.
+    pub fn distinct_letters(&self) -> usize {
+        self.answer.graphemes(true).collect::<HashSet<&str>>().len()
+    }
+
+    /// The words this game accepts as guesses.
+    pub fn dictionary(&self) -> &'static WordTrie {
+        match self.allowed {
+            WordList::Full => &GUESSES,
+            WordList::Common => &COMMON_TRIE,
+            WordList::Answers => &ANSWER_TRIE,
+        }
+    }
+
+    /// Whether some word this game accepts starts with the current guess.
+    pub fn prefix_is_viable(&self) -> bool {
+        self.dictionary().has_prefix(&self.curr)
+    }
+
+    /// Fills in the answer's first letter at the start of every guess, for good.
+    pub fn lock_first_letter(&mut self) {
+        self.locked = self.answer.chars().take(1).collect();
+        self.curr.clone_from(&self.locked);
+    }
+
+    /// How many letters at the start of the current guess are locked in place.
+    pub fn locked_len(&self) -> usize {
+        letter_count(&self.locked)
+    }
+
+    /// Types `c` into the current guess, unless it isn't a letter or the guess is
+    /// full. A combining accent goes on the letter before it, which takes the
+    /// composed form the word lists use where there is one.
+    pub fn input(&mut self, c: char) {
+        if let Some(typed) = typed_into(&self.curr, c) {
+            if letter_count(&typed) <= self.length() {
+                self.curr = typed;
+            }
+        }
+    }
+
+    /// Types in pasted text a letter at a time, so that whatever came along with
+    /// the word, such as spaces or a line break, is left out.
+    pub fn paste(&mut self, text: &str) {
+        for c in text.chars() {
+            self.input(c);
+        }
+    }
+
+    pub fn erase(&mut self) {
+        if letter_count(&self.curr) > self.locked_len() {
+            erase_letter(&mut self.curr);
+        }
+    }
+
+    /// Fills in the rest of the current guess when only one word could finish it.
+    pub fn complete(&mut self) {
+        if let Some(word) = self.dictionary().unique_completion(&self.curr) {
+            self.curr = word;
+        }
+    }
+
+    /// Commits the current guess if it is a known word. A guess set whole rather
+    /// than typed, such as one read from a file, that leaves out the locked
+    /// letters has them put in front if there is room, and is turned down if
+    /// there isn't.
+    pub fn guess(&mut self) -> GuessResult {
+        if !self.curr.starts_with(&self.locked)
+            && letter_count(&self.curr) + self.locked_len() <= self.length()
+        {
+            self.curr.insert_str(0, &self.locked);
+        }
+
+        let len = letter_count(&self.curr);
+
+        if len < self.length() {
+            return GuessResult::TooShort;
+        } else if len > self.length() {
+            return GuessResult::TooLong;
+        } else if let Some((idx, c)) = self
+            .locked
+            .graphemes(true)
+            .zip(self.curr.graphemes(true))
+            .enumerate()
+            .find_map(|(idx, (c, typed))| (c != typed).then_some((idx, c)))
+        {
+            return GuessResult::MissingGreen(idx, c.to_string());
+        } else if !self.dictionary().contains(&self.curr) {
+            return GuessResult::NotAWord;
+        } else if self.guesses.contains(&self.curr) {
+            return GuessResult::Repeated;
+        } else if let Some(c) = self.curr.chars().find(|c| self.banned.contains(c)) {
+            return GuessResult::Banned(c);
+        } else if let Some(missing) = self.hard_mode_violation() {
+            return missing;
+        }
+
+        if let Some(candidates) = &mut self.candidates {
+            let (_, survivors) = adversarial_feedback(candidates, &self.curr);
+
+            // every survivor scores all previous guesses the same way
+            self.answer = survivors[0].to_string();
+            *candidates = survivors;
+        }
+
+        self.clues.push(Some(score_guess(&self.answer, &self.curr)));
+        self.guesses.push(std::mem::take(&mut self.curr));
+        self.start_row();
+        GuessResult::Accepted
+    }
+
+    /// How the current guess breaks hard mode, if it does: every green has to stay
+    /// where it was found, and every letter found has to be used at least as many
+    /// times as any one guess found it. Ultra-hard mode also keeps grey letters
+    /// out, beyond the copies found, and yellow letters out of where they were found.
+    fn hard_mode_violation(&self) -> Option<GuessResult> {
+        if !self.hard && !self.ultra_hard {
+            return None;
+        }
+
+        let curr: Vec<&str> = self.curr.graphemes(true).collect();
+        let history = self.feedback_history();
+
+        for (guess, clues) in &history {
+            for (idx, (c, clue)) in guess.graphemes(true).zip(clues).enumerate() {
+                if *clue == Clue::Green && curr[idx] != c {
+                    return Some(GuessResult::MissingGreen(idx, c.to_string()));
+                }
+            }
+        }
+
+        for (guess, clues) in &history {
+            for c in guess.graphemes(true) {
+                let found = guess
+                    .graphemes(true)
+                    .zip(clues)
+                    .filter(|&(g, &clue)| g == c && clue != Clue::Grey)
+                    .count();
+
+                if curr.iter().filter(|&&g| g == c).count() < found {
+                    return Some(GuessResult::MissingLetter(c.to_string()));
+                }
+            }
+        }
+
+        if !self.ultra_hard {
+            return None;
+        }
+
+        for (guess, clues) in &history {
+            for (idx, (c, clue)) in guess.graphemes(true).zip(clues).enumerate() {
+                if *clue == Clue::Yellow && curr[idx] == c {
+                    return Some(GuessResult::YellowSpot(idx, c.to_string()));
+                }
+            }
+        }
+
+        for (guess, clues) in &history {
+            // a grey copy means the answer has no more than the copies found
+            for (c, _) in guess
+                .graphemes(true)
+                .zip(clues)
+                .filter(|&(_, &clue)| clue == Clue::Grey)
+            {
+                let found = guess
+                    .graphemes(true)
+                    .zip(clues)
+                    .filter(|&(g, &clue)| g == c && clue != Clue::Grey)
+                    .count();
+
+                if curr.iter().filter(|&&g| g == c).count() > found {
+                    return Some(GuessResult::GreyLetter(c.to_string(), found));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Burns the current row as a wrong guess, leaving an empty placeholder in its place.
+    pub fn burn_guess(&mut self) {
+        self.clues.push(None);
+        self.guesses.push(String::new());
+        self.curr.clear();
+        self.start_row();
+    }
+
+    /// Takes back the last guess, giving it back, as though it was never made.
+    /// Hints stay spent. Adversarial games can't take guesses back, since the
+    /// answer has already been narrowed down by them.
+    pub fn undo(&mut self) -> Option<String> {
+        if self.candidates.is_some() {
+            return None;
+        }
+
+        let guess = self.guesses.pop()?;
+        self.clues.truncate(self.guesses.len());
+        self.curr.clone_from(&self.locked);
+        self.undos += 1;
+        Some(guess)
+    }
+
+    /// Fills in the locked letters of the next guess, if there is one.
+    fn start_row(&mut self) {
+        if self.won().is_none() {
+            self.curr.clone_from(&self.locked);
+        }
+    }
+
+    /// Whether `hint` can be shown, either because it already was or because the
+    /// hint budget isn't spent yet.
+    pub fn can_hint(&self, hint: Hint) -> bool {
+        self.hints_shown.contains(&hint)
+            || self.hint_budget.is_none_or(|budget| self.hints < budget)
+    }
+
+    /// Counts `hint` as taken the first time it is shown.
+    fn take_hint(&mut self, hint: Hint) {
+        if self.hints_shown.insert(hint) {
+            self.hints += 1;
+        }
+    }
+
+    /// Reveals the category of the answer, counting as a hint the first time it is asked for.
+    pub fn category_hint(&mut self) -> Option<&'static str> {
+        self.take_hint(Hint::Category);
+        category(&self.answer)
+    }
+
+    /// Reveals how many answers are anagrams of the answer, along with one of
+    /// them if there are any, counting as a hint the first time it is asked for.
+    pub fn anagram_hint(&mut self) -> (usize, Option<&'static str>) {
+        self.take_hint(Hint::Anagram);
+        let example = anagrams(&self.answer, &ANSWERS).first().copied();
+        (anagram_count(&self.answer, &ANSWERS), example)
+    }
+
+    /// Reveals a letter of the answer the guesses haven't put in its place,
+    /// preferring one they haven't found at all, and counts it as a hint. Gives
+    /// the position and the letter, or `None` once there is nothing left to
+    /// reveal or while the answer isn't settled yet.
+    pub fn letter_hint(&mut self) -> Option<(usize, String)> {
+        if self.candidates.is_some() {
+            return None;
+        }
+
+        let coverage = self.answer_coverage();
+        let idx = [Clue::Grey, Clue::Yellow].into_iter().find_map(|clue| {
+            (0..self.length()).find(|idx| coverage[*idx] == clue && !self.revealed.contains(idx))
+        })?;
+
+        self.revealed.insert(idx);
+        self.hints += 1;
+        self.answer
+            .graphemes(true)
+            .nth(idx)
+            .map(|c| (idx, c.to_string()))
+    }
+
+    pub fn won(&self) -> Option<bool> {
+        if self.guesses.last() == Some(&self.answer) {
+            Some(true)
+        } else if self.guesses.len() >= self.max_guesses {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Every guess so far alongside its clues, leaving out burned rows.
+    pub fn feedback_history(&self) -> Vec<(&str, Vec<Clue>)> {
+        self.guesses
+            .iter()
+            .enumerate()
+            .filter_map(|(row, guess)| Some((guess.as_str(), self.clues(row)?)))
+            .collect()
+    }
+
+    /// Every guess made so far, with an empty one for each burned row.
+    pub fn guesses(&self) -> &[String] {
+        &self.guesses
+    }
+
+    /// The last guess made with the clues it got, unless its row was burned.
+    pub fn last_guess(&self) -> Option<Guess> {
+        let row = self.guesses.len().checked_sub(1)?;
+
+        Some(Guess {
+            word: self.guesses[row].clone(),
+            clues: self.clues(row)?,
+        })
+    }
+
+    /// The clues the guess in `row` got, or `None` for a burned row or one not
+    /// guessed yet.
+    pub fn clues(&self, row: usize) -> Option<Vec<Clue>> {
+        self.clues.get(row).cloned().flatten()
+    }
+
+    /// Replaces every guess, scoring them against the answer.
+    pub fn set_guesses(&mut self, guesses: Vec<String>) {
+        self.clues = guesses
+            .iter()
+            .map(|guess| (!guess.is_empty()).then(|| score_guess(&self.answer, guess)))
+            .collect();
+        self.guesses = guesses;
+    }
+
+    /// Takes the first guess off the board, to make room for another.
+    pub fn drop_oldest(&mut self) -> String {
+        self.clues.remove(0);
+        self.guesses.remove(0)
+    }
+
+    /// Puts back a guess taken off by `drop_oldest`.
+    pub fn restore_oldest(&mut self, guess: String) {
+        self.clues.insert(
+            0,
+            (!guess.is_empty()).then(|| score_guess(&self.answer, &guess)),
+        );
+        self.guesses.insert(0, guess);
+    }
+
+    /// The best each letter of the answer was ever found: green where some guess
+    /// had it in that very spot, yellow where the letter only turned up
+    /// elsewhere, and grey where it was never found at all.
+    pub fn answer_coverage(&self) -> Vec<Clue> {
+        let history = self.feedback_history();
+        let mut coverage = vec![Clue::Grey; self.length()];
+
+        for (idx, c) in self.answer.graphemes(true).enumerate() {
+            coverage[idx] = if history.iter().any(|(_, clues)| clues[idx] == Clue::Green) {
+                Clue::Green
+            } else if history.iter().any(|(guess, clues)| {
+                guess
+                    .graphemes(true)
+                    .zip(clues)
+                    .any(|(g, &clue)| g == c && clue != Clue::Grey)
+            }) {
+                Clue::Yellow
+            } else {
+                Clue::Grey
+            };
+        }
+
+        coverage
+    }
+
+    /// Which tiles of the last guess told something that the guesses before it
+    /// hadn't, rather than confirming what was already known. A yellow or green
+    /// is new when its letter had never been tried there, or when it shows the
+    /// answer has more copies of the letter than any earlier guess did, and a
+    /// grey is new until the letter has turned grey once.
+    pub fn new_information(&self) -> Vec<bool> {
+        let mut history = self.feedback_history();
+
+        let Some((guess, clues)) = history.pop() else {
+            return vec![false; self.length()];
+        };
+
+        let found = |guess: &str, clues: &[Clue], c: char| {
+            guess
+                .chars()
+                .zip(clues)
+                .filter(|&(g, &clue)| g == c && clue != Clue::Grey)
+                .count()
+        };
+
+        let mut new = vec![false; clues.len()];
+
+        for (idx, (c, &clue)) in guess.chars().zip(&clues).enumerate() {
+            let tried_here = history
+                .iter()
+                .any(|(guess, _)| guess.chars().nth(idx) == Some(c));
+
+            new[idx] = match clue {
+                Clue::Grey => !history.iter().any(|(guess, clues)| {
+                    guess
+                        .chars()
+                        .zip(clues)
+                        .any(|(g, &clue)| g == c && clue == Clue::Grey)
+                }),
+                _ if !tried_here => true,
+                _ => {
+                    let known = history
+                        .iter()
+                        .map(|(guess, clues)| found(guess, clues, c))
+                        .max()
+                        .unwrap_or(0);
+
+                    found(guess, &clues, c) > known
+                }
+            };
+        }
+
+        new
+    }
+
+    /// How many answers were still possible after each row, burned rows
+    /// leaving the count as it was.
+    pub fn remaining_per_row(&self) -> Vec<usize> {
+        let mut remaining = ANSWERS.clone();
+
+        self.guesses
+            .iter()
+            .enumerate()
+            .map(|(row, guess)| {
+                if let Some(clues) = self.clues(row) {
+                    remaining.retain(|answer| score_guess(answer, guess) == clues);
+                }
+
+                remaining.len()
+            })
+            .collect()
+    }
+
+    /// A letter of the current guess that the guesses before it showed isn't in the answer.
+    pub fn ruled_out_letter(&self) -> Option<char> {
+        let states = self.letter_states();
+        self.curr
+            .chars()
+            .find(|c| states.get(c) == Some(&Clue::Grey))
+    }
+
+    /// The best clue each guessed letter has earned so far.
+    pub fn letter_states(&self) -> HashMap<char, Clue> {
+        let mut states = HashMap::new();
+
+        for (guess, clues) in self.feedback_history() {
+            for (c, clue) in guess.chars().zip(clues) {
+                let state = states.entry(c).or_insert(clue);
+                *state = clue.min(*state);
+            }
+        }
+
+        states
+    }
+
+    /// What the guesses so far say about each letter typed into the current
+    /// guess: green where one found it in that same spot, grey where the letter
+    /// is known not to be in the answer at all, and nothing otherwise.
+    pub fn input_clues(&self) -> Vec<Option<Clue>> {
+        let history = self.feedback_history();
+        let states = self.letter_states();
+
+        self.curr
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| {
+                let green = history.iter().any(|(guess, clues)| {
+                    clues[idx] == Clue::Green && guess.chars().nth(idx) == Some(c)
+                });
+
+                if green {
+                    Some(Clue::Green)
+                } else if states.get(&c) == Some(&Clue::Grey) {
+                    Some(Clue::Grey)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// The letters of the word lists still possible at each position given all
+    /// the feedback so far. A green pins its position down to the one letter,
+    /// while a yellow or grey rules its letter out only where it was, unless the
+    /// answer is known to have no more copies of the letter than have been found
+    /// green, in which case it is ruled out of every other position too.
+    pub fn position_candidates(&self) -> Vec<BTreeSet<char>> {
+        let mut positions = vec![ALPHABET.clone(); self.length()];
+        // exact number of copies in the answer, for letters that have turned grey
+        let mut copies: HashMap<char, usize> = HashMap::new();
+
+        for (guess, clues) in self.feedback_history() {
+            for ((position, c), &clue) in positions.iter_mut().zip(guess.chars()).zip(&clues) {
+                if clue == Clue::Green {
+                    *position = BTreeSet::from([c]);
+                } else {
+                    position.remove(&c);
+                }
+
+                if clue == Clue::Grey {
+                    let found = guess
+                        .chars()
+                        .zip(&clues)
+                        .filter(|&(g, &clue)| g == c && clue != Clue::Grey)
+                        .count();
+                    copies.insert(c, found);
+                }
+            }
+        }
+
+        for (c, copies) in copies {
+            let pinned = positions
+                .iter()
+                .filter(|&position| *position == BTreeSet::from([c]));
+
+            if pinned.count() >= copies {
+                for position in &mut positions {
+                    if position.len() > 1 {
+                        position.remove(&c);
+                    }
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Positions each letter is known not to be at because it turned up yellow there.
+    pub fn yellow_exclusions(&self) -> HashMap<char, BTreeSet<usize>> {
+        let mut exclusions: HashMap<char, BTreeSet<usize>> = HashMap::new();
+
+        for (guess, clues) in self.feedback_history() {
+            for (idx, (c, clue)) in guess.chars().zip(clues).enumerate() {
+                if clue == Clue::Yellow {
+                    exclusions.entry(c).or_default().insert(idx);
+                }
+            }
+        }
+
+        exclusions
+    }
+
+    /// The result as emoji squares under a `Wordle 1234 4/6` header, numbered by
+    /// the daily puzzle or the seed, giving away nothing but the colors. Burned
+    /// rows show up as all grey squares. With `ascii`, the squares are drawn as
+    /// `#`, `+` and `.` instead.
+    pub fn share_grid(&self, ascii: bool) -> String {
+        let score = match self.won() {
+            Some(true) => self.guesses.len().to_string(),
+            _ => String::from("X"),
+        };
+
+        let mut grid = match (self.day, self.seed) {
+            (Some(day), _) => format!("Wordle {day} {score}/{}\n\n", self.max_guesses),
+            (None, Some(seed)) => format!("Wordle {seed} {score}/{}\n\n", self.max_guesses),
+            (None, None) => format!("Wordle {score}/{}\n\n", self.max_guesses),
+        };
+
+        for row in 0..self.guesses.len() {
+            let clues = self.clues(row).unwrap_or(vec![Clue::Grey; self.length()]);
+
+            grid.extend(
+                clues
+                    .into_iter()
+                    .map(if ascii { Clue::ascii } else { Clue::emoji }),
+            );
+            grid.push('\n');
+        }
+
+        grid
+    }
+
+    /// One grep-able line describing the game, e.g. `result=win guesses=4 answer=crane`.
+    pub fn summary_line(&self) -> String {
+        let result = match self.won() {
+            Some(true) => "win",
+            Some(false) => "loss",
+            None => "forfeit",
+        };
+
+        let mut line = format!(
+            "result={result} guesses={} answer={}",
+            self.guesses.len(),
+            self.answer
+        );
+
+        if let Some(seed) = self.seed {
+            line.push_str(&format!(" seed={seed}"));
+        }
+
+        if let Some(day) = self.day {
+            line.push_str(&format!(" day={day}"));
+        }
+
+        line
+    }
+}
+
+#[cfg(feature = "random")]
+impl Default for Wordle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shows the board as one `CRANE GYBBB` line per guess, without giving away the answer.
+impl fmt::Display for Wordle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (guess, clues) in self.feedback_history() {
+            writeln!(f, "{} {}", guess.to_uppercase(), clues_to_string(&clues))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Wordle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wordle")
+            .field("answer", &self.answer)
+            .field("curr", &self.curr)
+            .field("guesses", &self.guesses)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver;
+
+    /// Guesses an absurdle game may take, the same as a usual game, when each
+    /// guess is the one that says the most about the answers still open.
+    const ABSURDLE_BUDGET: usize = 6;
+
+    fn clues(feedback: &str) -> Vec<Clue> {
+        feedback
+            .chars()
+            .map(|c| Clue::from_letter(c).unwrap())
+            .collect()
+    }
+
+    /// A game against `answer` with `guesses` made, each of which has to be accepted.
+    fn played(answer: &str, guesses: &[&str]) -> Wordle {
+        let mut wordle = Wordle::with_answer(answer);
+
+        for guess in guesses {
+            wordle.curr = guess.to_string();
+            assert_eq!(wordle.guess(), GuessResult::Accepted, "{guess}");
+        }
+
+        wordle
+    }
+
+    #[test]
+    fn score_guess_matches_greens_before_yellows() {
+        assert_eq!(score_guess("crane", "crane"), clues("GGGGG"));
+        assert_eq!(score_guess("crane", "nacre"), clues("YYYYG"));
+        // one `e` in the answer, taken by the green, so the other one is grey
+        assert_eq!(score_guess("crane", "geese"), clues("BBBBG"));
+        // the one `l` of the answer is green, leaving none for the first `l`
+        assert_eq!(score_guess("plane", "llama"), clues("BGGBB"));
+        assert_eq!(clues_to_string(&score_guess("abbey", "babes")), "YYGGB");
+    }
+
+    #[test]
+    fn adversarial_feedback_keeps_a_consistent_candidate() {
+        let mut candidates = ANSWERS.clone();
+        let mut history = Vec::new();
+
+        for guess in ["crane", "moist", "pudgy", "lymph", "whack"] {
+            let (clues, survivors) = adversarial_feedback(&candidates, guess);
+            history.push((guess, clues));
+
+            assert!(!survivors.is_empty());
+            for survivor in &survivors {
+                assert!(history
+                    .iter()
+                    .all(|(guess, clues)| score_guess(survivor, guess) == *clues));
+            }
+
+            candidates = survivors;
+        }
+    }
+
+    #[test]
+    fn adversarial_feedback_dodges_until_forced() {
+        let (clues, survivors) = adversarial_feedback(&["crane", "crate"], "crane");
+        assert!(!is_win(&clues));
+        assert_eq!(survivors, ["crate"]);
+
+        let (clues, survivors) = adversarial_feedback(&["crate"], "crate");
+        assert!(is_win(&clues));
+        assert_eq!(survivors, ["crate"]);
+
+        // a guess outside what is left can't come back green either
+        let (clues, _) = adversarial_feedback(&["crate"], "crane");
+        assert!(!is_win(&clues));
+    }
+
+    #[test]
+    fn absurdle_is_solved_within_budget() {
+        let mut wordle = Wordle::absurdle();
+        wordle.max_guesses = ABSURDLE_BUDGET;
+        wordle.curr = String::from("crane");
+
+        while wordle.won().is_none() {
+            assert_eq!(wordle.guess(), GuessResult::Accepted);
+
+            let candidates = wordle.candidates.clone().unwrap();
+            let clues = wordle.clues(wordle.guesses.len() - 1).unwrap();
+
+            // all green only ever comes once nothing else is left
+            if is_win(&clues) {
+                assert_eq!(candidates, [wordle.guesses.last().unwrap().as_str()]);
+            }
+
+            if let Some(guess) = solver::information_guess(&candidates) {
+                wordle.curr = guess.to_string();
+            }
+        }
+
+        assert_eq!(wordle.won(), Some(true));
+    }
+
+    #[test]
+    fn display_shows_each_guess_with_its_clues() {
+        let wordle = played("crane", &["slate", "crane"]);
+        assert_eq!(wordle.to_string(), "SLATE BBGBG\nCRANE GGGGG\n");
+        assert_eq!(Wordle::with_answer("crane").to_string(), "");
+    }
+
+    #[test]
+    fn debug_shows_the_answer_and_guesses() {
+        let mut wordle = played("crane", &["slate"]);
+        wordle.input('c');
+
+        let debug = format!("{wordle:?}");
+        assert!(debug.starts_with(r#"Wordle { answer: "crane", curr: "c", guesses: ["slate"]"#));
+        assert!(debug.ends_with(".. }"));
+    }
+
+    #[test]
+    fn mixed_case_answers_can_be_won() {
+        let mut wordle = Wordle::with_answer("CrAnE");
+        assert_eq!(wordle.answer, "crane");
+
+        for c in "CRANE".chars() {
+            wordle.input(c);
+        }
+
+        assert_eq!(wordle.guess(), GuessResult::Accepted);
+        assert_eq!(wordle.won(), Some(true));
+    }
+
+    #[test]
+    fn list_words_are_lowercase_letters() {
+        assert!(is_list_word("crane"));
+        assert!(is_list_word("élève"));
+        assert!(!is_list_word("Crane"));
+        assert!(!is_list_word("cr4ne"));
+        assert!(!is_list_word("cra"));
+    }
+
+    #[test]
+    fn yellow_exclusions_gather_where_each_yellow_was() {
+        // READY: yellow R and E, green A. OUTER: yellow E and R
+        let wordle = played("crane", &["ready", "outer"]);
+        let exclusions = wordle.yellow_exclusions();
+
+        assert_eq!(exclusions[&'r'], BTreeSet::from([0, 4]));
+        assert_eq!(exclusions[&'e'], BTreeSet::from([1, 3]));
+        assert!(!exclusions.contains_key(&'a'));
+    }
+
+    #[test]
+    fn letter_states_keep_the_best_clue() {
+        let wordle = played("crane", &["arose", "crate"]);
+        let states = wordle.letter_states();
+
+        assert_eq!(states[&'r'], Clue::Green);
+        assert_eq!(states[&'a'], Clue::Green);
+        assert_eq!(states[&'o'], Clue::Grey);
+        assert!(!states.contains_key(&'z'));
+    }
+
+    #[test]
+    #[cfg(feature = "random")]
+    fn random_games_can_be_replayed_from_their_seed() {
+        let wordle = Wordle::new();
+        let seed = wordle.seed.expect("random games are seeded");
+
+        assert_eq!(Wordle::with_seed(seed).answer, wordle.answer);
+    }
+
+    #[test]
+    fn seeds_always_pick_the_same_answer() {
+        assert_eq!(Wordle::with_seed(5).answer, "forge");
+        assert_eq!(Wordle::with_seed(5).seed, Some(5));
+    }
+
+    #[test]
+    fn letters_in_common_counts_repeats_once_per_match() {
+        assert_eq!(letters_in_common("crane", "nacre"), 5);
+        assert_eq!(letters_in_common("crane", "moist"), 0);
+        // one `e` in the answer matches only one of the three guessed
+        assert_eq!(letters_in_common("crane", "geese"), 1);
+        // both `l`s of the guess match the two of the answer
+        assert_eq!(letters_in_common("llama", "label"), 3);
+        assert_eq!(letters_in_common("label", "llama"), 3);
+    }
+
+    #[test]
+    fn words_in_category_are_answers_with_that_tag() {
+        let colors = words_in_category("color");
+
+        assert!(colors.contains(&"amber"));
+        assert!(colors
+            .iter()
+            .all(|word| category(word) == Some("color") && ANSWERS.contains(word)));
+        assert!(categories().contains("color"));
+        assert!(words_in_category("no such tag").is_empty());
+    }
+
+    #[test]
+    fn answers_only_turns_down_other_words() {
+        let mut wordle = Wordle::with_answer("crane");
+        wordle.allowed = WordList::Answers;
+
+        wordle.curr = String::from("slabs");
+        assert!(GUESSES.contains("slabs"));
+        assert_eq!(wordle.guess(), GuessResult::NotAWord);
+
+        wordle.curr = String::from("slab");
+        assert!(!wordle.prefix_is_viable());
+
+        wordle.curr = String::from("slate");
+        assert_eq!(wordle.guess(), GuessResult::Accepted);
+    }
+
+    #[test]
+    fn bundled_answers_can_all_be_guessed() {
+        assert!(check_lists().is_ok());
+        assert!(ANSWERS.iter().all(|answer| GUESSES.contains(answer)));
+    }
+
+    #[test]
+    fn emoji_grids_read_both_greys_and_skip_the_header() {
+        let grid = "Wordle 1,000 3/6\n\n⬛🟨⬛⬛⬛\n⬜🟩⬜🟨⬜\n🟧🟧🟧🟦🟦\n";
+
+        assert_eq!(
+            parse_emoji_grid(grid),
+            [clues("BYBBB"), clues("BGBYB"), clues("GGGYY")]
+        );
+        // too short a row to be one
+        assert!(parse_emoji_grid("🟩🟩🟩").is_empty());
+    }
+
+    #[test]
+    fn shared_grids_read_back_the_same() {
+        let wordle = played("crane", &["slate", "crane"]);
+
+        assert_eq!(
+            parse_emoji_grid(&wordle.share_grid(false)),
+            [clues("BBGBG"), clues("GGGGG")]
+        );
+    }
+
+    #[test]
+    fn common_only_takes_everyday_words() {
+        let mut wordle = Wordle::with_answer("crane");
+        wordle.allowed = WordList::Common;
+
+        wordle.curr = String::from("aahed");
+        assert!(GUESSES.contains("aahed"));
+        assert_eq!(wordle.guess(), GuessResult::NotAWord);
+
+        // not an answer, but common enough
+        wordle.curr = String::from("backs");
+        assert_eq!(wordle.guess(), GuessResult::Accepted);
+    }
+
+    #[test]
+    fn common_words_can_all_be_guessed() {
+        assert_eq!(uncommon_common_word(), None);
+    }
+
+    #[test]
+    fn daily_salts_give_their_own_order() {
+        assert_eq!(daily_seed(None), 0);
+        // the FNV-1a hash of "a"
+        assert_eq!(daily_seed(Some("a")), 0xaf63dc4c8601ec8c);
+
+        let first = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let days: Vec<NaiveDate> = first.iter_days().take(10).collect();
+        let answers = |salt| {
+            days.iter()
+                .map(|&day| Wordle::daily(day, salt, false).answer)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(answers(Some("club")), answers(Some("club")));
+        assert_ne!(answers(Some("club")), answers(None));
+        assert_eq!(Wordle::daily(first, Some("club"), false).day, Some(986));
+    }
+
+    #[test]
+    fn schedules_read_a_date_and_answer_per_line() {
+        let schedule =
+            Schedule::parse("# club puzzles\n\n2024-03-01\tCRANE\n2024-03-02\t slate \n").unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+
+        assert_eq!(schedule.get(day(1)), Some("crane"));
+        assert_eq!(schedule.get(day(2)), Some("slate"));
+        assert_eq!(schedule.get(day(3)), None);
+    }
+
+    #[test]
+    fn schedules_report_the_bad_line() {
+        let error = |text| Schedule::parse(text).err().unwrap();
+
+        assert_eq!(
+            error("2024-03-01 crane"),
+            "line 1: expected a date and an answer separated by a tab"
+        );
+        assert_eq!(
+            error("#\n03/01/2024\tcrane"),
+            "line 2: `03/01/2024` is not a YYYY-MM-DD date"
+        );
+        assert_eq!(
+            error("2024-03-01\tqqqqq"),
+            "line 1: `qqqqq` is not a valid guess"
+        );
+        assert_eq!(
+            error("2024-03-01\tcrane\n2024-03-01\tslate"),
+            "line 2: 2024-03-01 is scheduled twice"
+        );
+    }
+
+    #[test]
+    fn banned_letters_turn_down_guesses() {
+        let mut wordle = Wordle::with_answer("crane");
+        wordle.banned = BTreeSet::from(['s', 'e']);
+
+        wordle.curr = String::from("slate");
+        assert_eq!(wordle.guess(), GuessResult::Banned('s'));
+        assert!(wordle.guesses.is_empty());
+
+        wordle.curr = String::from("moist");
+        assert_eq!(wordle.guess(), GuessResult::Banned('s'));
+        wordle.curr = String::from("dumpy");
+        assert_eq!(wordle.guess(), GuessResult::Accepted);
+        assert!(wordle.answer_is_banned());
+    }
+
+    #[test]
+    fn easy_start_keeps_the_first_letter() {
+        let mut wordle = Wordle::with_answer("crane");
+        wordle.lock_first_letter();
+        assert_eq!(wordle.curr, "c");
+
+        wordle.erase();
+        assert_eq!(wordle.curr, "c");
+
+        // set whole, with and without the locked letter
+        wordle.curr = String::from("slate");
+        assert_eq!(
+            wordle.guess(),
+            GuessResult::MissingGreen(0, String::from("c"))
+        );
+        wordle.curr = String::from("rate");
+        assert_eq!(wordle.guess(), GuessResult::Accepted);
+        assert_eq!(wordle.guesses, ["crate"]);
+        assert_eq!(wordle.curr, "c");
+    }
+
+    #[test]
+    fn hard_mode_goes_by_whole_letters() {
+        // an accented letter written as a plain one and a combining accent
+        let acute = "e\u{301}";
+        let mut wordle = Wordle::with_answer(&format!("{acute}tape"));
+        wordle.hard = true;
+
+        let guess = format!("{acute}crai");
+        wordle.clues.push(Some(score_guess(&wordle.answer, &guess)));
+        wordle.guesses.push(guess);
+
+        wordle.curr = String::from("etape");
+        assert_eq!(
+            wordle.hard_mode_violation(),
+            Some(GuessResult::MissingGreen(0, acute.to_string()))
+        );
+
+        wordle.curr = format!("{acute}lude");
+        assert_eq!(
+            wordle.hard_mode_violation(),
+            Some(GuessResult::MissingLetter(String::from("a")))
+        );
+
+        wordle.curr = format!("{acute}tape");
+        assert_eq!(wordle.hard_mode_violation(), None);
+
+        let mut fresh = Wordle::with_answer(&format!("{acute}tape"));
+        assert_eq!(fresh.letter_hint(), Some((0, acute.to_string())));
+    }
+
+    #[test]
+    fn new_information_skips_what_was_already_known() {
+        assert_eq!(Wordle::with_answer("crane").new_information(), [false; 5]);
+        assert_eq!(played("crane", &["slate"]).new_information(), [true; 5]);
+        // A, E and the grey T were all found by SLATE
+        assert_eq!(
+            played("crane", &["slate", "crate"]).new_information(),
+            [true, true, false, false, false]
+        );
+    }
+
+    #[test]
+    fn position_candidates_follow_the_clues() {
+        let positions = played("crane", &["slate"]).position_candidates();
+
+        assert_eq!(positions[2], BTreeSet::from(['a']));
+        assert_eq!(positions[4], BTreeSet::from(['e']));
+        // greys are ruled out everywhere, the green A only where it was found
+        assert!(!positions[0].contains(&'s') && !positions[3].contains(&'t'));
+        assert!(positions[0].contains(&'a') && positions[0].contains(&'c'));
+    }
+
+    #[test]
+    fn position_candidates_count_copies_of_repeats() {
+        // the one E of the answer is the green one, so it can't be anywhere else
+        let positions = played("crane", &["geese"]).position_candidates();
+        assert!(positions[..4]
+            .iter()
+            .all(|position| !position.contains(&'e')));
+
+        // the yellow E leaves room for one more besides the green
+        let positions = played("eerie", &["tepee"]).position_candidates();
+        assert!(positions[0].contains(&'e'));
+        assert!(!positions[3].contains(&'e'));
+    }
+
+    #[test]
+    fn answer_coverage_keeps_how_well_each_letter_was_found() {
+        assert_eq!(
+            played("crane", &["slate", "rocks"]).answer_coverage(),
+            clues("YYGBG")
+        );
+        assert_eq!(
+            Wordle::with_answer("crane").answer_coverage(),
+            clues("BBBBB")
+        );
+    }
+
+    #[test]
+    fn ascii_shares_swap_the_emoji_for_plain_characters() {
+        let mut wordle = played("crane", &["slate", "crane"]);
+        assert_eq!(wordle.share_grid(true), "Wordle 2/6\n\n..#.#\n#####\n");
+
+        wordle = played("crane", &["trace"]);
+        wordle.max_guesses = 1;
+        wordle.seed = Some(7);
+        assert_eq!(wordle.share_grid(true), "Wordle 7 X/1\n\n.##+#\n");
+    }
+
+    #[test]
+    fn burned_rows_run_the_game_out() {
+        let mut wordle = Wordle::with_answer("crane");
+
+        for _ in 0..wordle.max_guesses {
+            assert_eq!(wordle.won(), None);
+            wordle.burn_guess();
+        }
+
+        assert_eq!(wordle.won(), Some(false));
+        assert_eq!(wordle.guesses, vec![String::new(); wordle.max_guesses]);
+        assert!(wordle.feedback_history().is_empty());
+
+        // guessed rows are kept around the burned ones
+        let mut wordle = played("crane", &["slate"]);
+        wordle.burn_guess();
+        assert_eq!(wordle.feedback_history(), [("slate", clues("BBGBG"))]);
+    }
+}
@@ -0,0 +1,206 @@
+//! Word lists read from disk at startup, for builds with the `runtime-wordlists` feature.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+use crate::error::WordleError;
+
+/// Every list the game needs.
+const NAMES: [&str; 17] = [
+    "answers",
+    "guesses",
+    "common",
+    "categories",
+    "definitions",
+    "rude",
+    "frequencies",
+    "answers-4",
+    "guesses-4",
+    "answers-6",
+    "guesses-6",
+    "answers-7",
+    "guesses-7",
+    "answers-8",
+    "guesses-8",
+    "answers-fr",
+    "guesses-fr",
+];
+
+/// How many words are checked between reports of progress.
+const PROGRESS_EVERY: usize = 1000;
+
+lazy_static! {
+    /// The lists `check` has read, by name.
+    static ref LOADED: Mutex<HashMap<&'static str, &'static str>> = Mutex::new(HashMap::new());
+}
+
+/// Directories searched for the lists, in order.
+fn search_path() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = std::env::var_os("WORDLE_WORDLISTS")
+        .map(PathBuf::from)
+        .into_iter()
+        .collect();
+
+    dirs.extend(dirs::data_dir().map(|dir| dir.join("wordle")));
+    dirs.push(PathBuf::from("/usr/local/share/wordle"));
+    dirs.push(PathBuf::from("/usr/share/wordle"));
+    dirs
+}
+
+/// Reads the list called `name` from the first directory that has it. The
+/// contents are kept for the rest of the program, like an embedded list.
+pub fn read(name: &str) -> Result<&'static str, WordleError> {
+    read_from(name, search_path())
+}
+
+/// Reads the list called `name` from the first of `dirs` that has it.
+fn read_from(name: &str, dirs: Vec<PathBuf>) -> Result<&'static str, WordleError> {
+    for dir in &dirs {
+        match fs::read_to_string(dir.join(name)) {
+            Ok(list) => return Ok(Box::leak(list.into_boxed_str())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(source) => {
+                return Err(WordleError::File {
+                    path: dir.join(name),
+                    source,
+                })
+            }
+        }
+    }
+
+    Err(WordleError::MissingList {
+        name: name.to_string(),
+        searched: dirs,
+    })
+}
+
+/// The list called `name`, which `check` must have loaded beforehand.
+pub fn load(name: &str) -> &'static str {
+    LOADED
+        .lock()
+        .unwrap()
+        .get(name)
+        .copied()
+        .unwrap_or_else(|| panic!("the `{name}` word list wasn't loaded"))
+}
+
+/// Whether `line` is a valid entry of the list called `name`: a word,
+/// followed by its tag in `categories`, its meaning in `definitions` or
+/// its rank in `frequencies`.
+fn is_valid(name: &str, line: &str) -> bool {
+    let word = match name {
+        "frequencies" => match line.split_once(' ') {
+            Some((word, rank)) if rank.parse::<u32>().is_ok() => word,
+            _ => return false,
+        },
+        "categories" | "definitions" => match line.split_once(' ') {
+            Some((word, tag)) if !tag.is_empty() => word,
+            _ => return false,
+        },
+        _ => line,
+    };
+
+    crate::wordle::is_list_word(word)
+}
+
+/// Reads and validates every list, so that a missing or broken one is reported
+/// up front rather than when it is first needed. `progress` is told how many
+/// words have been checked so far every now and then, and once more at the end.
+pub fn check(progress: impl FnMut(usize)) -> Result<(), WordleError> {
+    check_in(&search_path(), progress)
+}
+
+/// Reads and validates every list as `check` does, from `dirs`.
+fn check_in(dirs: &[PathBuf], mut progress: impl FnMut(usize)) -> Result<(), WordleError> {
+    let mut words = 0;
+
+    for name in NAMES {
+        let list = read_from(name, dirs.to_vec())?;
+
+        for (idx, line) in list.lines().enumerate() {
+            if !is_valid(name, line) {
+                return Err(WordleError::BadEntry {
+                    list: name.to_string(),
+                    line: idx + 1,
+                    entry: line.to_string(),
+                });
+            }
+
+            words += 1;
+
+            if words % PROGRESS_EVERY == 0 {
+                progress(words);
+            }
+        }
+
+        LOADED.lock().unwrap().insert(name, list);
+    }
+
+    progress(words);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_need_what_their_list_has_after_the_word() {
+        assert!(is_valid("answers", "crane"));
+        assert!(!is_valid("answers", "Crane"));
+        assert!(is_valid("frequencies", "crane 1042"));
+        assert!(!is_valid("frequencies", "crane often"));
+        assert!(is_valid("categories", "crane bird"));
+        assert!(!is_valid("categories", "crane "));
+        assert!(!is_valid("definitions", "crane"));
+    }
+
+    /// A directory of its own for the test called `name`, holding `lists`.
+    fn list_dir(name: &str, lists: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("wordle-lists-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for (list, contents) in lists {
+            fs::write(dir.join(list), contents).unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn missing_lists_name_where_they_were_looked_for() {
+        let empty = list_dir("empty", &[]);
+        let other = empty.join("nowhere");
+
+        let err = read_from("answers", vec![empty.clone(), other.clone()]).unwrap_err();
+        assert!(matches!(&err, WordleError::MissingList { name, .. } if name == "answers"));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "couldn't find the `answers` word list in any of: {}, {}; put it in \
+                 one of them, or point WORDLE_WORDLISTS at where it is",
+                empty.display(),
+                other.display()
+            )
+        );
+        fs::remove_dir_all(empty).unwrap();
+    }
+
+    #[test]
+    fn bad_entries_give_their_list_and_line() {
+        let dir = list_dir("bad", &[("answers", "crane\nslate\nMoist\n")]);
+
+        let err = check_in(std::slice::from_ref(&dir), |_| {}).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3 of the `answers` word list: bad entry `Moist`, every line has to \
+             start with a word of 4 to 8 letters in lowercase"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+//! `--deterministic` runs of the game, which have to come out byte for byte the
+//! same every time.
+
+#![cfg(feature = "terminal")]
+
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// A data directory of its own for `name`, holding a stats file with `stats`.
+fn data_dir(name: &str, stats: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wordle-test-{name}-{}", std::process::id()));
+    fs::create_dir_all(dir.join("wordle")).unwrap();
+    fs::write(dir.join("wordle").join("stats.json"), stats).unwrap();
+    dir
+}
+
+/// Everything printed by a run with `args`, given `input` as its guesses.
+fn run(data: &PathBuf, args: &[&str], input: &str) -> Vec<u8> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordle"))
+        .args(args)
+        .env("XDG_DATA_HOME", data)
+        .env_remove("WORDLE_OPTS")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    [output.stdout, output.stderr].concat()
+}
+
+#[test]
+fn json_logs_are_identical() {
+    let data = data_dir("json", r#"{"games":[]}"#);
+    let args = ["--deterministic", "--seed", "7", "--json"];
+    let input = "crane\nslate\nmoist\n";
+
+    let first = run(&data, &args, input);
+    let log = String::from_utf8_lossy(&first);
+    assert!(log.contains(r#""seed":7"#), "{log}");
+    assert!(log.contains(r#""word":"crane""#), "{log}");
+    assert_eq!(first, run(&data, &args, input));
+    fs::remove_dir_all(data).unwrap();
+}
+
+#[test]
+fn review_runs_are_identical() {
+    let data = data_dir("review", r#"{"games":[],"missed":["slate","crane"]}"#);
+    let args = ["--deterministic", "--review", "--plain"];
+    let input = "crane\nslate\n";
+
+    let first = run(&data, &args, input);
+    assert!(String::from_utf8_lossy(&first).contains("slate GGGGG"));
+    assert_eq!(first, run(&data, &args, input));
+    fs::remove_dir_all(data).unwrap();
+}
+
+#[test]
+fn stats_are_left_alone() {
+    let stats = r#"{"games":[]}"#;
+    let data = data_dir("stats", stats);
+
+    let args = ["--deterministic", "--accessible"];
+    let first = run(&data, &args, "crane\n");
+    assert_eq!(first, run(&data, &args, "crane\n"));
+
+    let kept = fs::read_to_string(data.join("wordle").join("stats.json")).unwrap();
+    assert_eq!(kept, stats);
+    fs::remove_dir_all(data).unwrap();
+}